@@ -1,4 +1,28 @@
 //! Zero-Copy message and listener support.
+//!
+//! [`BorrowedListener`] is the synchronous, zero-copy counterpart of
+//! [`Listener`](crate::Listener): its output may borrow from the input event,
+//! so projections like `&'a str` flow through the pipeline without cloning.
+//!
+//! # Pipeline
+//!
+//! ```rust,ignore
+//! // Project the content, keep commands, and handle them without copying.
+//! let hook = ContentListener
+//!     .filter(|content: &&str| content.starts_with('!'))
+//!     .and_then(CommandListener)
+//!     .handler(run_command);
+//!
+//! router.route(&event).await?; // `hook` is a `Hook<MessageEvent>`
+//! ```
+
+use crate::{
+    context::BorrowedFn,
+    error::BoxError,
+    hook::{Hook, HookResult},
+    message::Message,
+    response::IntoResponse,
+};
 
 /// A marker trait for borrowed messages.
 pub trait RawMessage<'a>: Send + Sync {}
@@ -7,7 +31,7 @@ pub trait RawMessage<'a>: Send + Sync {}
 impl<'a, T: Send + Sync + ?Sized> RawMessage<'a> for T {}
 
 /// A listener that can produce borrowed output from borrowed input (GAT).
-pub trait BorrowedListener<In>: Send + Sync + 'static {
+pub trait BorrowedListener<In: ?Sized>: Send + Sync + 'static {
     /// The output type, which may borrow from the input.
     type Output<'a>: RawMessage<'a>
     where
@@ -15,6 +39,56 @@ pub trait BorrowedListener<In>: Send + Sync + 'static {
 
     /// Inspect and optionally transform the input.
     fn listen<'a>(&self, event: &'a In) -> Option<Self::Output<'a>>;
+
+    /// Chains this listener with a listener over the referenced output.
+    ///
+    /// This listener must project a reference (`Output<'a> = &'a Mid`), which
+    /// the next listener inspects for the same lifetime.
+    fn and_then<Next>(self, next: Next) -> BorrowedChain<Self, Next>
+    where
+        Self: Sized,
+    {
+        BorrowedChain::new(self, next)
+    }
+
+    /// Filters the output of this listener.
+    fn filter<F>(self, predicate: F) -> BorrowedFilter<Self, F>
+    where
+        Self: Sized,
+        F: for<'a> Fn(&Self::Output<'a>) -> bool + Send + Sync + 'static,
+    {
+        BorrowedFilter {
+            listener: self,
+            predicate,
+        }
+    }
+
+    /// Transforms the output of this listener.
+    ///
+    /// The mapped output may keep borrowing from the input (see [`BorrowedMapFn`]).
+    fn map<F>(self, mapper: F) -> BorrowedMap<Self, F>
+    where
+        Self: Sized,
+        F: for<'a> BorrowedMapFn<Self::Output<'a>>,
+    {
+        BorrowedMap {
+            listener: self,
+            mapper,
+        }
+    }
+
+    /// Connects to an async handler function, producing a [`Hook`].
+    ///
+    /// The handler receives the borrowed output directly; the event is never cloned.
+    fn handler<H>(self, handler: H) -> BorrowedPipeline<Self, H>
+    where
+        Self: Sized,
+    {
+        BorrowedPipeline {
+            listener: self,
+            handler,
+        }
+    }
 }
 
 // Note: A blanket impl from Listener to BorrowedListener is not possible
@@ -35,3 +109,130 @@ impl<A, B> BorrowedChain<A, B> {
         Self { first, second }
     }
 }
+
+impl<In, Mid, A, B> BorrowedListener<In> for BorrowedChain<A, B>
+where
+    In: ?Sized + 'static,
+    Mid: ?Sized + 'static,
+    A: for<'a> BorrowedListener<In, Output<'a> = &'a Mid>,
+    B: BorrowedListener<Mid>,
+{
+    type Output<'a>
+        = B::Output<'a>
+    where
+        In: 'a;
+
+    fn listen<'a>(&self, event: &'a In) -> Option<Self::Output<'a>> {
+        let intermediate = self.first.listen(event)?;
+        self.second.listen(intermediate)
+    }
+}
+
+/// A borrowed listener that drops outputs rejected by a predicate.
+pub struct BorrowedFilter<L, F> {
+    listener: L,
+    predicate: F,
+}
+
+impl<In, L, F> BorrowedListener<In> for BorrowedFilter<L, F>
+where
+    In: ?Sized,
+    L: BorrowedListener<In>,
+    F: for<'a> Fn(&L::Output<'a>) -> bool + Send + Sync + 'static,
+{
+    type Output<'a>
+        = L::Output<'a>
+    where
+        In: 'a;
+
+    fn listen<'a>(&self, event: &'a In) -> Option<Self::Output<'a>> {
+        self.listener
+            .listen(event)
+            .filter(|output| (self.predicate)(output))
+    }
+}
+
+/// A mapping function whose output may borrow from its input.
+///
+/// This is implemented for every `Fn(In) -> Out`. Requiring
+/// `for<'a> BorrowedMapFn<L::Output<'a>>` lets the output type vary with the
+/// input's lifetime: function items such as `fn trim(s: &str) -> &str` keep
+/// borrowing from the event, while closures work whenever their output does
+/// not borrow from the argument.
+///
+/// The compiler cannot yet normalize such a lifetime-dependent output inside
+/// [`BorrowedListener::handler`]'s bounds, so maps that keep borrowing are
+/// limited to synchronous [`listen`](BorrowedListener::listen) use. To feed
+/// a handler, project the borrow with [`and_then`](BorrowedListener::and_then)
+/// instead, or map to an owned value.
+pub trait BorrowedMapFn<In>: Send + Sync + 'static {
+    /// The mapped output.
+    type Output: Send + Sync;
+
+    /// Map the input.
+    fn map(&self, input: In) -> Self::Output;
+}
+
+impl<F, In, Out> BorrowedMapFn<In> for F
+where
+    F: Fn(In) -> Out + Send + Sync + 'static,
+    Out: Send + Sync,
+{
+    type Output = Out;
+
+    fn map(&self, input: In) -> Out {
+        (self)(input)
+    }
+}
+
+/// A borrowed listener that transforms outputs.
+pub struct BorrowedMap<L, F> {
+    listener: L,
+    mapper: F,
+}
+
+impl<In, L, F> BorrowedListener<In> for BorrowedMap<L, F>
+where
+    In: ?Sized,
+    L: BorrowedListener<In>,
+    F: for<'a> BorrowedMapFn<L::Output<'a>>,
+{
+    type Output<'a>
+        = <F as BorrowedMapFn<L::Output<'a>>>::Output
+    where
+        In: 'a;
+
+    fn listen<'a>(&self, event: &'a In) -> Option<Self::Output<'a>> {
+        self.listener
+            .listen(event)
+            .map(|output| self.mapper.map(output))
+    }
+}
+
+/// A zero-copy pipeline of a [`BorrowedListener`] and an async handler.
+///
+/// Implements [`Hook`], so it can be registered in any router. The handler
+/// is any function satisfying [`BorrowedFn`] for the listener's output, and
+/// its result is converted with [`IntoResponse`].
+pub struct BorrowedPipeline<L, H> {
+    /// The borrowed listener.
+    pub listener: L,
+    /// The handler function.
+    pub handler: H,
+}
+
+impl<E, L, H, Out> Hook<E> for BorrowedPipeline<L, H>
+where
+    E: Message + Sync,
+    L: BorrowedListener<E>,
+    for<'a> L::Output<'a>: Send,
+    H: for<'a> BorrowedFn<'a, (L::Output<'a>,), Output = Out>,
+    Out: IntoResponse,
+{
+    async fn on_event(&self, event: &E) -> Result<HookResult, BoxError> {
+        match self.listener.listen(event) {
+            Some(output) => self.handler.call_borrowed((output,)).await.into_response(),
+            None => Ok(HookResult::Next),
+        }
+    }
+}
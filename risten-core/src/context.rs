@@ -26,14 +26,30 @@
 //! let handler = ExtractHandler::new(my_handler);
 //! ```
 
-use crate::message::Message;
+use crate::{error::BoxError, hook::HookResult, message::Message, response::IntoResponse};
+use std::borrow::Cow;
 use std::convert::Infallible;
 use std::future::Future;
 
 /// Error type for extraction failures.
+///
+/// When produced by an extractor-aware handler, the error records which
+/// argument failed (its position and type name) and keeps the extractor's
+/// original error as its [`source`](std::error::Error::source).
+///
+/// An extractor may also *reject* an event without failing: if its
+/// [`FromEvent::rejection`] resolves to a [`HookResult`] rather than an error,
+/// the resulting `ExtractError` carries that [`outcome`](Self::outcome) and
+/// converts back into it via [`IntoResponse`], so the handler is skipped
+/// instead of surfacing a hard error.
 #[derive(Debug)]
 pub struct ExtractError {
     message: String,
+    index: Option<usize>,
+    type_name: Option<&'static str>,
+    source: Option<BoxError>,
+    outcome: Option<HookResult>,
+    handler: Option<Cow<'static, str>>,
 }
 
 impl ExtractError {
@@ -41,22 +57,137 @@ impl ExtractError {
     pub fn new(message: impl Into<String>) -> Self {
         Self {
             message: message.into(),
+            index: None,
+            type_name: None,
+            source: None,
+            outcome: None,
+            handler: None,
+        }
+    }
+
+    /// Create an error for the extractor `T` at argument position `index`.
+    ///
+    /// `rejection` is the extractor's [`FromEvent::rejection`] for the failure.
+    /// If it resolves to a [`HookResult`], the error becomes a soft rejection
+    /// carrying that outcome; otherwise the resolved error is kept as the source.
+    pub fn rejected<T: ?Sized>(index: usize, rejection: impl IntoResponse) -> Self {
+        let (message, source, outcome) = match rejection.into_response() {
+            Ok(outcome) => (format!("rejected with {outcome:?}"), None, Some(outcome)),
+            Err(source) => (source.to_string(), Some(source), None),
+        };
+        Self {
+            message,
+            index: Some(index),
+            type_name: Some(std::any::type_name::<T>()),
+            source,
+            outcome,
+            handler: None,
+        }
+    }
+
+    /// Create a soft rejection that skips the handler without an error.
+    ///
+    /// Used when a handler declines an event before extraction, e.g. because
+    /// a subscription filter did not match.
+    pub fn skipped(message: impl Into<String>) -> Self {
+        Self {
+            outcome: Some(HookResult::Next),
+            ..Self::new(message)
         }
     }
 
+    /// Create a hard failure from an error raised while running the handler,
+    /// such as a timeout, keeping it as the [`source`](std::error::Error::source).
+    pub fn failed(source: impl Into<BoxError>) -> Self {
+        let source = source.into();
+        Self {
+            message: source.to_string(),
+            source: Some(source),
+            ..Self::new("")
+        }
+    }
+
+    /// Record the [name](crate::Handler::name) of the handler that failed.
+    pub fn with_handler(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.handler = Some(name.into());
+        self
+    }
+
+    /// Get the name of the handler that failed, if known.
+    pub fn handler(&self) -> Option<&str> {
+        self.handler.as_deref()
+    }
+
     /// Get the error message.
     pub fn message(&self) -> &str {
         &self.message
     }
+
+    /// Get the position of the argument that failed to extract, if known.
+    pub fn index(&self) -> Option<usize> {
+        self.index
+    }
+
+    /// Get the type name of the extractor that failed, if known.
+    pub fn type_name(&self) -> Option<&'static str> {
+        self.type_name
+    }
+
+    /// Get the propagation outcome of a soft rejection.
+    ///
+    /// Returns `None` for hard extraction failures.
+    pub fn outcome(&self) -> Option<HookResult> {
+        self.outcome
+    }
+
+    /// Returns `true` if this is a soft rejection rather than a failure.
+    pub fn is_rejection(&self) -> bool {
+        self.outcome.is_some()
+    }
+
+    /// Attempt to downcast the extractor's original error to a concrete type.
+    pub fn downcast_ref<T: std::error::Error + 'static>(&self) -> Option<&T> {
+        self.source.as_ref()?.downcast_ref::<T>()
+    }
+
+    /// Consume the error and return the extractor's original error, if any.
+    pub fn into_source(self) -> Option<BoxError> {
+        self.source
+    }
 }
 
 impl std::fmt::Display for ExtractError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "extraction failed: {}", self.message)
+        if let Some(handler) = &self.handler {
+            write!(f, "handler `{handler}`: ")?;
+        }
+        match (self.index, self.type_name) {
+            (Some(index), Some(type_name)) => write!(
+                f,
+                "failed to extract argument {index} (`{type_name}`): {}",
+                self.message
+            ),
+            _ => write!(f, "extraction failed: {}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for ExtractError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source
+            .as_deref()
+            .map(|e| e as &(dyn std::error::Error + 'static))
     }
 }
 
-impl std::error::Error for ExtractError {}
+impl IntoResponse for ExtractError {
+    fn into_response(self) -> Result<HookResult, BoxError> {
+        match self.outcome {
+            Some(outcome) => Ok(outcome),
+            None => Err(Box::new(self)),
+        }
+    }
+}
 
 /// A trait for extracting data from an event synchronously.
 ///
@@ -68,6 +199,21 @@ pub trait FromEvent<E>: Sized {
 
     /// Attempt to extract `Self` from the given event.
     fn from_event(event: &E) -> Result<Self, Self::Error>;
+
+    /// Decide how a failed extraction affects event propagation.
+    ///
+    /// The default treats every failure as a hard error. Override this to
+    /// reject the event softly instead, e.g. returning `HookResult::Next` so a
+    /// missing optional context skips the handler:
+    ///
+    /// ```rust,ignore
+    /// fn rejection(_error: Self::Error) -> impl IntoResponse {
+    ///     HookResult::Next
+    /// }
+    /// ```
+    fn rejection(error: Self::Error) -> impl IntoResponse {
+        Err::<HookResult, _>(error)
+    }
 }
 
 /// A trait for extracting data from an event asynchronously.
@@ -106,6 +252,13 @@ pub trait AsyncFromEvent<E>: Sized + Send {
 
     /// Asynchronously extract `Self` from the given event.
     fn from_event(event: &E) -> impl Future<Output = Result<Self, Self::Error>> + Send;
+
+    /// Decide how a failed extraction affects event propagation.
+    ///
+    /// See [`FromEvent::rejection`].
+    fn rejection(error: Self::Error) -> impl IntoResponse {
+        Err::<HookResult, _>(error)
+    }
 }
 
 // Blanket implementation: Any FromEvent automatically implements AsyncFromEvent
@@ -119,6 +272,10 @@ where
     async fn from_event(event: &E) -> Result<Self, Self::Error> {
         T::from_event(event)
     }
+
+    fn rejection(error: Self::Error) -> impl IntoResponse {
+        T::rejection(error)
+    }
 }
 
 /// A trait for extracting data from an event using GATs (Generic Associated Types).
@@ -151,6 +308,13 @@ pub trait FromEventGat<E> {
 
     /// Extract data from the event.
     fn extract<'a>(event: &'a E) -> Result<Self::Output<'a>, Self::Error>;
+
+    /// Decide how a failed extraction affects event propagation.
+    ///
+    /// See [`FromEvent::rejection`].
+    fn rejection(error: Self::Error) -> impl IntoResponse {
+        Err::<HookResult, _>(error)
+    }
 }
 
 // Blanket Implementations
@@ -198,7 +362,7 @@ impl<E: Clone> FromEvent<E> for Event<E> {
 
 /// Macro to implement FromEvent for tuples of extractors.
 macro_rules! impl_from_event_tuple {
-    ($($T:ident),+) => {
+    ($($T:ident: $idx:tt),+) => {
         impl<E, $($T,)+> FromEvent<E> for ($($T,)+)
         where
             $(
@@ -212,26 +376,30 @@ macro_rules! impl_from_event_tuple {
             fn from_event(event: &E) -> Result<Self, Self::Error> {
                 $(
                     let $T = $T::from_event(event)
-                        .map_err(|e| ExtractError::new(e.to_string()))?;
+                        .map_err(|e| ExtractError::rejected::<$T>($idx, $T::rejection(e)))?;
                 )+
                 Ok(($($T,)+))
             }
+
+            fn rejection(error: ExtractError) -> impl IntoResponse {
+                error
+            }
         }
     };
 }
 
-impl_from_event_tuple!(T1);
-impl_from_event_tuple!(T1, T2);
-impl_from_event_tuple!(T1, T2, T3);
-impl_from_event_tuple!(T1, T2, T3, T4);
-impl_from_event_tuple!(T1, T2, T3, T4, T5);
-impl_from_event_tuple!(T1, T2, T3, T4, T5, T6);
-impl_from_event_tuple!(T1, T2, T3, T4, T5, T6, T7);
-impl_from_event_tuple!(T1, T2, T3, T4, T5, T6, T7, T8);
-impl_from_event_tuple!(T1, T2, T3, T4, T5, T6, T7, T8, T9);
-impl_from_event_tuple!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10);
-impl_from_event_tuple!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11);
-impl_from_event_tuple!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12);
+impl_from_event_tuple!(T1: 0);
+impl_from_event_tuple!(T1: 0, T2: 1);
+impl_from_event_tuple!(T1: 0, T2: 1, T3: 2);
+impl_from_event_tuple!(T1: 0, T2: 1, T3: 2, T4: 3);
+impl_from_event_tuple!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4);
+impl_from_event_tuple!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5);
+impl_from_event_tuple!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6);
+impl_from_event_tuple!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7);
+impl_from_event_tuple!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7, T9: 8);
+impl_from_event_tuple!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7, T9: 8, T10: 9);
+impl_from_event_tuple!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7, T9: 8, T10: 9, T11: 10);
+impl_from_event_tuple!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7, T9: 8, T10: 9, T11: 10, T12: 11);
 
 // Handler Integration
 
@@ -326,7 +494,7 @@ macro_rules! impl_extract_handler {
     };
 
     // Recursive case: 1+ arguments
-    ($($T:ident),+) => {
+    ($($T:ident: $idx:tt),+) => {
         impl<F, E, $($T,)+ Out, Fut> crate::Handler<E> for ExtractHandler<F, E, ($($T,)+)>
         where
             E: Message + Sync,
@@ -345,7 +513,7 @@ macro_rules! impl_extract_handler {
                 $(
                     let $T = $T::from_event(&input)
                         .await
-                        .map_err(|e| ExtractError::new(e.to_string()))?;
+                        .map_err(|e| ExtractError::rejected::<$T>($idx, $T::rejection(e)))?;
                 )+
                 Ok((self.func)($($T,)+).await)
             }
@@ -354,18 +522,18 @@ macro_rules! impl_extract_handler {
 }
 
 impl_extract_handler!();
-impl_extract_handler!(T1);
-impl_extract_handler!(T1, T2);
-impl_extract_handler!(T1, T2, T3);
-impl_extract_handler!(T1, T2, T3, T4);
-impl_extract_handler!(T1, T2, T3, T4, T5);
-impl_extract_handler!(T1, T2, T3, T4, T5, T6);
-impl_extract_handler!(T1, T2, T3, T4, T5, T6, T7);
-impl_extract_handler!(T1, T2, T3, T4, T5, T6, T7, T8);
-impl_extract_handler!(T1, T2, T3, T4, T5, T6, T7, T8, T9);
-impl_extract_handler!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10);
-impl_extract_handler!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11);
-impl_extract_handler!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12);
+impl_extract_handler!(T1: 0);
+impl_extract_handler!(T1: 0, T2: 1);
+impl_extract_handler!(T1: 0, T2: 1, T3: 2);
+impl_extract_handler!(T1: 0, T2: 1, T3: 2, T4: 3);
+impl_extract_handler!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4);
+impl_extract_handler!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5);
+impl_extract_handler!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6);
+impl_extract_handler!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7);
+impl_extract_handler!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7, T9: 8);
+impl_extract_handler!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7, T9: 8, T10: 9);
+impl_extract_handler!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7, T9: 8, T10: 9, T11: 10);
+impl_extract_handler!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7, T9: 8, T10: 9, T11: 10, T12: 11);
 
 /// Macro to implement Handler for SyncExtractHandler with N arguments.
 macro_rules! impl_sync_extract_handler {
@@ -386,7 +554,7 @@ macro_rules! impl_sync_extract_handler {
     };
 
     // Recursive case: 1+ arguments (sync extraction only)
-    ($($T:ident),+) => {
+    ($($T:ident: $idx:tt),+) => {
         impl<F, E, $($T,)+ Out> crate::Handler<E> for SyncExtractHandler<F, E, ($($T,)+)>
         where
             E: Message + Sync,
@@ -403,7 +571,7 @@ macro_rules! impl_sync_extract_handler {
             async fn call(&self, input: E) -> Self::Output {
                 $(
                     let $T = $T::from_event(&input)
-                        .map_err(|e| ExtractError::new(e.to_string()))?;
+                        .map_err(|e| ExtractError::rejected::<$T>($idx, $T::rejection(e)))?;
                 )+
                 Ok((self.func)($($T,)+))
             }
@@ -412,18 +580,18 @@ macro_rules! impl_sync_extract_handler {
 }
 
 impl_sync_extract_handler!();
-impl_sync_extract_handler!(T1);
-impl_sync_extract_handler!(T1, T2);
-impl_sync_extract_handler!(T1, T2, T3);
-impl_sync_extract_handler!(T1, T2, T3, T4);
-impl_sync_extract_handler!(T1, T2, T3, T4, T5);
-impl_sync_extract_handler!(T1, T2, T3, T4, T5, T6);
-impl_sync_extract_handler!(T1, T2, T3, T4, T5, T6, T7);
-impl_sync_extract_handler!(T1, T2, T3, T4, T5, T6, T7, T8);
-impl_sync_extract_handler!(T1, T2, T3, T4, T5, T6, T7, T8, T9);
-impl_sync_extract_handler!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10);
-impl_sync_extract_handler!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11);
-impl_sync_extract_handler!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12);
+impl_sync_extract_handler!(T1: 0);
+impl_sync_extract_handler!(T1: 0, T2: 1);
+impl_sync_extract_handler!(T1: 0, T2: 1, T3: 2);
+impl_sync_extract_handler!(T1: 0, T2: 1, T3: 2, T4: 3);
+impl_sync_extract_handler!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4);
+impl_sync_extract_handler!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5);
+impl_sync_extract_handler!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6);
+impl_sync_extract_handler!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7);
+impl_sync_extract_handler!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7, T9: 8);
+impl_sync_extract_handler!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7, T9: 8, T10: 9);
+impl_sync_extract_handler!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7, T9: 8, T10: 9, T11: 10);
+impl_sync_extract_handler!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7, T9: 8, T10: 9, T11: 10, T12: 11);
 
 // ============================================================================
 // Zero-Copy Extraction (Phase 2)
@@ -457,35 +625,128 @@ impl<E> FromEventGat<E> for RefEvent<'_, E> {
     }
 }
 
-/// A handler that uses GAT-based extractors for zero-copy event processing.
+/// Adapts an owned [`FromEvent`] extractor for use among borrowed arguments.
+///
+/// `Owned<T>` implements [`FromEventGat`] with `Output<'a> = T`, so zero-copy
+/// handlers can mix owned and borrowed extractors in one argument list. It is
+/// only used as a marker in the handler's argument types; the function itself
+/// receives a plain `T`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// async fn on_message(content: &str, user: User) { ... }
+///
+/// let handler = BorrowedExtractHandler::<_, MessageEvent, (Content, Owned<User>)>::new(on_message);
+/// ```
+pub struct Owned<T>(std::marker::PhantomData<fn() -> T>);
+
+impl<E, T> FromEventGat<E> for Owned<T>
+where
+    T: FromEvent<E>,
+{
+    type Output<'a>
+        = T
+    where
+        E: 'a;
+    type Error = T::Error;
+
+    fn extract(event: &E) -> Result<T, Self::Error> {
+        T::from_event(event)
+    }
+
+    fn rejection(error: Self::Error) -> impl IntoResponse {
+        T::rejection(error)
+    }
+}
+
+/// An async function whose arguments may borrow from the event for `'a`.
+///
+/// This is implemented for every `Fn(A1, ..., An) -> Fut` whose future may
+/// capture its arguments. Requiring `for<'a> BorrowedFn<'a, Args>` lets a
+/// handler's future hold references into the event it is processing, which a
+/// plain `Fn(Args) -> Fut` bound cannot express.
+///
+/// Closures returning `async move` blocks that capture borrowed arguments are
+/// not general over `'a`; use `async fn` items for async zero-copy handlers.
+pub trait BorrowedFn<'a, Args>: Send + Sync + 'static {
+    /// The value produced by the function's future.
+    type Output;
+    /// The future returned by the function, borrowing for `'a`.
+    type Future: Future<Output = Self::Output> + Send + 'a;
+
+    /// Call the function with the extracted arguments.
+    fn call_borrowed(&self, args: Args) -> Self::Future;
+}
+
+/// Macro to implement BorrowedFn for functions with N arguments.
+macro_rules! impl_borrowed_fn {
+    ($($A:ident),+) => {
+        impl<'a, F, Fut, $($A,)+> BorrowedFn<'a, ($($A,)+)> for F
+        where
+            F: Fn($($A),+) -> Fut + Send + Sync + 'static,
+            Fut: Future + Send + 'a,
+        {
+            type Output = Fut::Output;
+            type Future = Fut;
+
+            #[allow(non_snake_case)]
+            fn call_borrowed(&self, ($($A,)+): ($($A,)+)) -> Fut {
+                (self)($($A),+)
+            }
+        }
+    };
+}
+
+impl_borrowed_fn!(A1);
+impl_borrowed_fn!(A1, A2);
+impl_borrowed_fn!(A1, A2, A3);
+impl_borrowed_fn!(A1, A2, A3, A4);
+impl_borrowed_fn!(A1, A2, A3, A4, A5);
+impl_borrowed_fn!(A1, A2, A3, A4, A5, A6);
+impl_borrowed_fn!(A1, A2, A3, A4, A5, A6, A7);
+impl_borrowed_fn!(A1, A2, A3, A4, A5, A6, A7, A8);
+impl_borrowed_fn!(A1, A2, A3, A4, A5, A6, A7, A8, A9);
+impl_borrowed_fn!(A1, A2, A3, A4, A5, A6, A7, A8, A9, A10);
+impl_borrowed_fn!(A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11);
+impl_borrowed_fn!(A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12);
+
+/// A handler that uses GAT-based extractors for zero-copy event processing (async version).
 ///
 /// Unlike [`ExtractHandler`] which requires owned extractor outputs,
 /// `BorrowedExtractHandler` uses [`FromEventGat`] to enable extractors
-/// that borrow from the input event.
+/// that borrow from the input event. Owned [`FromEvent`] extractors can be
+/// mixed in by wrapping them in [`Owned`].
 ///
 /// # Example
 ///
 /// ```rust,ignore
 /// use risten_core::{BorrowedExtractHandler, RefEvent};
 ///
-/// // Zero-copy handler - receives reference to event
-/// let handler = BorrowedExtractHandler::new(|event: RefEvent<'_, MyEvent>| async move {
-///     println!("Processing: {}", event.0.content);
-///     Ok(())
-/// });
+/// // Zero-copy handler - receives references into the event
+/// async fn on_message(event: RefEvent<'_, MyEvent>, content: &str, user: User) {
+///     println!("{}: {}", user.name, content);
+/// }
+///
+/// let handler =
+///     BorrowedExtractHandler::<_, MyEvent, (RefEvent<MyEvent>, Content, Owned<User>)>::new(on_message);
 /// ```
 ///
 /// # Limitations
 ///
 /// Due to Rust's lifetime constraints with async functions, the handler
-/// function must be `for<'a>` bounded, meaning it must work with any lifetime.
+/// function must be `for<'a>` bounded (see [`BorrowedFn`]), meaning it must
+/// work with any lifetime. `async fn` items satisfy this; closures returning
+/// borrowing `async` blocks do not.
+///
+/// For synchronous functions, use [`SyncBorrowedExtractHandler`].
 pub struct BorrowedExtractHandler<F, E, Args> {
     func: F,
     _marker: std::marker::PhantomData<(E, Args)>,
 }
 
 impl<F, E, Args> BorrowedExtractHandler<F, E, Args> {
-    /// Create a new borrowed extract handler.
+    /// Create a new borrowed extract handler from an async function.
     pub fn new(func: F) -> Self {
         Self {
             func,
@@ -494,23 +755,114 @@ impl<F, E, Args> BorrowedExtractHandler<F, E, Args> {
     }
 }
 
-// Implementation for 1 GAT extractor
-impl<F, E, T1, Out> crate::Handler<E> for BorrowedExtractHandler<F, E, (T1,)>
-where
-    E: crate::Message + Sync,
-    T1: FromEventGat<E> + Send + Sync + 'static,
-    for<'a> T1::Output<'a>: Send,
-    F: for<'a> Fn(T1::Output<'a>) -> Out + Send + Sync + 'static,
-    Out: crate::handler::HandlerResult,
-{
-    type Output = Result<Out, ExtractError>;
+/// A handler that uses GAT-based extractors for zero-copy event processing (sync version).
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use risten_core::{RefEvent, SyncBorrowedExtractHandler};
+///
+/// let handler = SyncBorrowedExtractHandler::new(|event: RefEvent<'_, MyEvent>| {
+///     event.0.content.len()
+/// });
+/// ```
+///
+/// For asynchronous functions, use [`BorrowedExtractHandler`].
+pub struct SyncBorrowedExtractHandler<F, E, Args> {
+    func: F,
+    _marker: std::marker::PhantomData<(E, Args)>,
+}
 
-    async fn call(&self, input: E) -> Self::Output {
-        let extracted = T1::extract(&input).map_err(|e| ExtractError::new(e.to_string()))?;
-        Ok((self.func)(extracted))
+impl<F, E, Args> SyncBorrowedExtractHandler<F, E, Args> {
+    /// Create a new sync borrowed extract handler from a synchronous function.
+    pub fn new(func: F) -> Self {
+        Self {
+            func,
+            _marker: std::marker::PhantomData,
+        }
     }
 }
 
+/// Macro to implement Handler for BorrowedExtractHandler with N arguments.
+macro_rules! impl_borrowed_extract_handler {
+    ($($T:ident: $idx:tt),+) => {
+        impl<F, E, $($T,)+ Out> crate::Handler<E> for BorrowedExtractHandler<F, E, ($($T,)+)>
+        where
+            E: Message + Sync,
+            $(
+                $T: FromEventGat<E> + Send + Sync + 'static,
+                for<'a> $T::Output<'a>: Send,
+            )+
+            F: for<'a> BorrowedFn<'a, ($($T::Output<'a>,)+), Output = Out>,
+            Out: crate::handler::HandlerResult,
+        {
+            type Output = Result<Out, ExtractError>;
+
+            #[allow(non_snake_case)]
+            async fn call(&self, input: E) -> Self::Output {
+                $(
+                    let $T = $T::extract(&input)
+                        .map_err(|e| ExtractError::rejected::<$T>($idx, $T::rejection(e)))?;
+                )+
+                Ok(self.func.call_borrowed(($($T,)+)).await)
+            }
+        }
+    };
+}
+
+impl_borrowed_extract_handler!(T1: 0);
+impl_borrowed_extract_handler!(T1: 0, T2: 1);
+impl_borrowed_extract_handler!(T1: 0, T2: 1, T3: 2);
+impl_borrowed_extract_handler!(T1: 0, T2: 1, T3: 2, T4: 3);
+impl_borrowed_extract_handler!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4);
+impl_borrowed_extract_handler!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5);
+impl_borrowed_extract_handler!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6);
+impl_borrowed_extract_handler!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7);
+impl_borrowed_extract_handler!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7, T9: 8);
+impl_borrowed_extract_handler!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7, T9: 8, T10: 9);
+impl_borrowed_extract_handler!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7, T9: 8, T10: 9, T11: 10);
+impl_borrowed_extract_handler!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7, T9: 8, T10: 9, T11: 10, T12: 11);
+
+/// Macro to implement Handler for SyncBorrowedExtractHandler with N arguments.
+macro_rules! impl_sync_borrowed_extract_handler {
+    ($($T:ident: $idx:tt),+) => {
+        impl<F, E, $($T,)+ Out> crate::Handler<E> for SyncBorrowedExtractHandler<F, E, ($($T,)+)>
+        where
+            E: Message + Sync,
+            $(
+                $T: FromEventGat<E> + Send + Sync + 'static,
+                for<'a> $T::Output<'a>: Send,
+            )+
+            F: for<'a> Fn($($T::Output<'a>),+) -> Out + Send + Sync + 'static,
+            Out: crate::handler::HandlerResult,
+        {
+            type Output = Result<Out, ExtractError>;
+
+            #[allow(non_snake_case)]
+            async fn call(&self, input: E) -> Self::Output {
+                $(
+                    let $T = $T::extract(&input)
+                        .map_err(|e| ExtractError::rejected::<$T>($idx, $T::rejection(e)))?;
+                )+
+                Ok((self.func)($($T),+))
+            }
+        }
+    };
+}
+
+impl_sync_borrowed_extract_handler!(T1: 0);
+impl_sync_borrowed_extract_handler!(T1: 0, T2: 1);
+impl_sync_borrowed_extract_handler!(T1: 0, T2: 1, T3: 2);
+impl_sync_borrowed_extract_handler!(T1: 0, T2: 1, T3: 2, T4: 3);
+impl_sync_borrowed_extract_handler!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4);
+impl_sync_borrowed_extract_handler!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5);
+impl_sync_borrowed_extract_handler!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6);
+impl_sync_borrowed_extract_handler!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7);
+impl_sync_borrowed_extract_handler!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7, T9: 8);
+impl_sync_borrowed_extract_handler!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7, T9: 8, T10: 9);
+impl_sync_borrowed_extract_handler!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7, T9: 8, T10: 9, T11: 10);
+impl_sync_borrowed_extract_handler!(T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7, T9: 8, T10: 9, T11: 10, T12: 11);
+
 #[cfg(test)]
 mod borrowed_tests {
     use super::*;
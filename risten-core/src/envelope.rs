@@ -0,0 +1,384 @@
+//! # Event Envelope
+//!
+//! Standard metadata for events: an [`Envelope`] wraps a user event with its
+//! id, creation timestamp, correlation and causation ids and string headers.
+//!
+//! Handlers of `Envelope<E>` can extract the metadata with the [`EventId`],
+//! [`Timestamp`], [`Headers`], [`Correlation`] and [`TraceContext`] extractors. Envelopes
+//! emitted while handling another envelope inherit its correlation id, and
+//! record it as their cause.
+
+use crate::{
+    context::{ExtractError, FromEvent},
+    message::Message,
+    trace::{TRACEPARENT, TRACESTATE, TraceContext},
+};
+use std::{
+    collections::HashMap,
+    convert::Infallible,
+    fmt,
+    hash::{BuildHasher, RandomState},
+    ops::Deref,
+    str::FromStr,
+    sync::{
+        LazyLock,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::SystemTime,
+};
+
+/// A unique event identifier.
+///
+/// Formatted like a UUID. Ids are random per process and unique within it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EventId(u128);
+
+impl EventId {
+    /// Generate a new unique id.
+    pub fn new() -> Self {
+        static STATE: LazyLock<RandomState> = LazyLock::new(RandomState::new);
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+        let count = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let high = STATE.hash_one((count, 0u8));
+        let low = STATE.hash_one((count, 1u8));
+        Self((u128::from(high) << 64) | u128::from(low))
+    }
+
+    /// Create an id from its numeric value, e.g. one received over the wire.
+    pub const fn from_u128(value: u128) -> Self {
+        Self(value)
+    }
+
+    /// The numeric value of this id.
+    pub const fn as_u128(&self) -> u128 {
+        self.0
+    }
+}
+
+impl Default for EventId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Error returned when parsing an [`EventId`] fails.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("invalid event id: expected 32 hex digits, optionally hyphenated like a UUID")]
+pub struct ParseEventIdError;
+
+/// Parses the hyphenated form produced by `Display`, or 32 bare hex digits.
+impl FromStr for EventId {
+    type Err = ParseEventIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex = match s.len() {
+            32 => s.to_owned(),
+            36 if [8, 13, 18, 23].iter().all(|&i| s.as_bytes()[i] == b'-') => s.replace('-', ""),
+            _ => return Err(ParseEventIdError),
+        };
+        if hex.len() != 32 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(ParseEventIdError);
+        }
+        u128::from_str_radix(&hex, 16)
+            .map(Self)
+            .map_err(|_| ParseEventIdError)
+    }
+}
+
+impl fmt::Display for EventId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hex = format!("{:032x}", self.0);
+        write!(
+            f,
+            "{}-{}-{}-{}-{}",
+            &hex[..8],
+            &hex[8..12],
+            &hex[12..16],
+            &hex[16..20],
+            &hex[20..]
+        )
+    }
+}
+
+/// The time an event was created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp(pub SystemTime);
+
+/// String headers attached to an event.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Headers(pub HashMap<String, String>);
+
+impl Headers {
+    /// The value of header `name`, if present.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.0.get(name).map(String::as_str)
+    }
+}
+
+/// The correlation and causation ids of an event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Correlation {
+    /// The id shared by every event of the same flow.
+    pub correlation_id: EventId,
+    /// The id of the event whose handling produced this one.
+    pub causation_id: Option<EventId>,
+}
+
+/// The metadata carried by an [`Envelope`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Metadata {
+    id: EventId,
+    timestamp: SystemTime,
+    correlation_id: EventId,
+    causation_id: Option<EventId>,
+    headers: HashMap<String, String>,
+}
+
+impl Metadata {
+    /// Metadata for a new event starting its own flow.
+    pub fn new() -> Self {
+        let id = EventId::new();
+        Self {
+            id,
+            timestamp: SystemTime::now(),
+            correlation_id: id,
+            causation_id: None,
+            headers: HashMap::new(),
+        }
+    }
+
+    /// Metadata rebuilt from its parts, e.g. after crossing a queue.
+    pub fn from_parts(
+        id: EventId,
+        timestamp: SystemTime,
+        correlation: Correlation,
+        headers: HashMap<String, String>,
+    ) -> Self {
+        Self {
+            id,
+            timestamp,
+            correlation_id: correlation.correlation_id,
+            causation_id: correlation.causation_id,
+            headers,
+        }
+    }
+
+    /// The id of the event.
+    pub fn id(&self) -> EventId {
+        self.id
+    }
+
+    /// When the event was created.
+    pub fn timestamp(&self) -> SystemTime {
+        self.timestamp
+    }
+
+    /// The correlation and causation ids of the event.
+    pub fn correlation(&self) -> Correlation {
+        Correlation {
+            correlation_id: self.correlation_id,
+            causation_id: self.causation_id,
+        }
+    }
+
+    /// The headers of the event.
+    pub fn headers(&self) -> &HashMap<String, String> {
+        &self.headers
+    }
+
+    /// The value of header `name`, if present.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).map(String::as_str)
+    }
+
+    /// Set header `name`, returning its previous value.
+    pub fn insert_header(
+        &mut self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Option<String> {
+        self.headers.insert(name.into(), value.into())
+    }
+
+    /// Record `parent` as the cause of this event, joining its flow.
+    pub fn set_cause(&mut self, parent: &Metadata) {
+        self.correlation_id = parent.correlation_id;
+        self.causation_id = Some(parent.id);
+    }
+
+    /// Whether a cause has been recorded for this event.
+    pub fn has_cause(&self) -> bool {
+        self.causation_id.is_some()
+    }
+
+    /// The trace context stored in the `traceparent` and `tracestate` headers.
+    ///
+    /// Returns `None` if the event carries no valid `traceparent`.
+    pub fn trace_context(&self) -> Option<TraceContext> {
+        TraceContext::parse(self.header(TRACEPARENT)?, self.header(TRACESTATE))
+    }
+
+    /// Store `context` in the `traceparent` and `tracestate` headers.
+    pub fn set_trace_context(&mut self, context: &TraceContext) {
+        self.insert_header(TRACEPARENT, context.traceparent());
+        match context.tracestate() {
+            Some(state) => self.insert_header(TRACESTATE, state),
+            None => self.headers.remove(TRACESTATE),
+        };
+    }
+}
+
+impl Default for Metadata {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A message wrapper carrying standard event [`Metadata`].
+///
+/// Dereferences to the wrapped event.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let envelope = Envelope::new(OrderPlaced { id: 7 }).with_header("tenant", "acme");
+/// router.route(&envelope).await?;
+///
+/// #[subscribe]
+/// async fn on_order(order: &Envelope<OrderPlaced>, id: EventId, headers: Headers) {
+///     println!("{id}: order {} for {:?}", order.id, headers.get("tenant"));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Envelope<E> {
+    event: E,
+    metadata: Metadata,
+}
+
+impl<E> Envelope<E> {
+    /// Wrap `event` with fresh metadata.
+    pub fn new(event: E) -> Self {
+        Self {
+            event,
+            metadata: Metadata::new(),
+        }
+    }
+
+    /// Wrap `event` with existing metadata, e.g. received over the wire.
+    ///
+    /// The inverse of [`into_parts`](Self::into_parts).
+    pub fn from_parts(event: E, metadata: Metadata) -> Self {
+        Self { event, metadata }
+    }
+
+    /// Wrap `event` as a consequence of the event described by `parent`.
+    pub fn caused_by(event: E, parent: &Metadata) -> Self {
+        let mut envelope = Self::new(event);
+        envelope.metadata.set_cause(parent);
+        envelope
+    }
+
+    /// Attach the trace context of the span producing the event.
+    pub fn with_trace_context(mut self, context: &TraceContext) -> Self {
+        self.metadata.set_trace_context(context);
+        self
+    }
+
+    /// Add a header.
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata.insert_header(name, value);
+        self
+    }
+
+    /// The wrapped event.
+    pub fn event(&self) -> &E {
+        &self.event
+    }
+
+    /// The metadata of the event.
+    pub fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
+    /// Mutable access to the metadata of the event.
+    pub fn metadata_mut(&mut self) -> &mut Metadata {
+        &mut self.metadata
+    }
+
+    /// Unwrap the event, discarding its metadata.
+    pub fn into_inner(self) -> E {
+        self.event
+    }
+
+    /// Split the envelope into its event and metadata.
+    pub fn into_parts(self) -> (E, Metadata) {
+        (self.event, self.metadata)
+    }
+}
+
+impl<E> Deref for Envelope<E> {
+    type Target = E;
+
+    fn deref(&self) -> &E {
+        &self.event
+    }
+}
+
+impl<E: Message> Message for Envelope<E> {
+    fn variant(&self) -> Option<&'static str> {
+        self.event.variant()
+    }
+
+    fn metadata(&self) -> Option<&Metadata> {
+        Some(&self.metadata)
+    }
+
+    fn metadata_mut(&mut self) -> Option<&mut Metadata> {
+        Some(&mut self.metadata)
+    }
+}
+
+impl<E> FromEvent<Envelope<E>> for EventId {
+    type Error = Infallible;
+
+    fn from_event(event: &Envelope<E>) -> Result<Self, Self::Error> {
+        Ok(event.metadata.id)
+    }
+}
+
+impl<E> FromEvent<Envelope<E>> for Timestamp {
+    type Error = Infallible;
+
+    fn from_event(event: &Envelope<E>) -> Result<Self, Self::Error> {
+        Ok(Timestamp(event.metadata.timestamp))
+    }
+}
+
+impl<E> FromEvent<Envelope<E>> for Headers {
+    type Error = Infallible;
+
+    fn from_event(event: &Envelope<E>) -> Result<Self, Self::Error> {
+        Ok(Headers(event.metadata.headers.clone()))
+    }
+}
+
+impl<E> FromEvent<Envelope<E>> for Correlation {
+    type Error = Infallible;
+
+    fn from_event(event: &Envelope<E>) -> Result<Self, Self::Error> {
+        Ok(event.metadata.correlation())
+    }
+}
+
+/// Rejects envelopes without a valid trace context.
+impl<E> FromEvent<Envelope<E>> for TraceContext {
+    type Error = ExtractError;
+
+    fn from_event(event: &Envelope<E>) -> Result<Self, Self::Error> {
+        event
+            .metadata
+            .trace_context()
+            .ok_or_else(|| ExtractError::new("event carries no trace context"))
+    }
+}
@@ -6,7 +6,7 @@
 //! - [`DispatchError`] - Errors during event dispatch
 //! - [`HookError`] - Errors from individual hooks
 
-use std::time::Duration;
+use std::{borrow::Cow, time::Duration};
 use thiserror::Error;
 
 /// A boxed error type for dynamic error handling.
@@ -35,6 +35,16 @@ pub enum RoutingError {
     #[error("listener error")]
     Listener(#[source] BoxError),
 
+    /// A hook failed.
+    #[error("hook `{name}` failed")]
+    Hook {
+        /// The [name](crate::Hook::name) of the hook.
+        name: Cow<'static, str>,
+        /// The error returned by the hook.
+        #[source]
+        source: BoxError,
+    },
+
     /// A hook signaled early stop.
     #[error("hook returned early stop")]
     EarlyStop,
@@ -48,6 +58,16 @@ pub enum RoutingError {
     Shutdown,
 }
 
+impl RoutingError {
+    /// The name of the hook that failed, if known.
+    pub fn hook_name(&self) -> Option<&str> {
+        match self {
+            RoutingError::Hook { name, .. } => Some(name),
+            _ => None,
+        }
+    }
+}
+
 /// Errors that can occur in hooks.
 #[derive(Error, Debug)]
 pub enum HookError {
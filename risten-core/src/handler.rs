@@ -3,6 +3,7 @@
 //! Wraps user-defined methods to inject framework-specific context.
 
 use crate::message::Message;
+use std::borrow::Cow;
 use std::future::Future;
 use std::pin::Pin;
 
@@ -20,6 +21,77 @@ pub trait Handler<In: Message>: Send + Sync + 'static {
     type Output: HandlerResult;
 
     fn call(&self, input: In) -> impl Future<Output = Self::Output> + Send;
+
+    /// The name of this handler in errors, logs and traces.
+    ///
+    /// Defaults to the type name; wrapper handlers report the name of the
+    /// handler they wrap.
+    fn name(&self) -> Cow<'static, str> {
+        Cow::Borrowed(std::any::type_name::<Self>())
+    }
+
+    /// Transforms the output of this handler.
+    fn map_output<F, Out>(self, mapper: F) -> MapOutput<Self, F>
+    where
+        Self: Sized,
+        Out: HandlerResult,
+        F: Fn(Self::Output) -> Out + Send + Sync + 'static,
+    {
+        MapOutput {
+            handler: self,
+            mapper,
+        }
+    }
+
+    /// Pipes the output of this handler into another handler.
+    fn and_then<Next>(self, next: Next) -> AndThen<Self, Next>
+    where
+        Self: Sized,
+        Self::Output: Message,
+        Next: Handler<Self::Output>,
+    {
+        AndThen {
+            first: self,
+            second: next,
+        }
+    }
+
+    /// Transforms the error of a handler returning `Result`.
+    fn map_err<F, T, Err, NewErr>(self, mapper: F) -> MapErr<Self, F>
+    where
+        Self: Sized + Handler<In, Output = Result<T, Err>>,
+        NewErr: Send + Sync + 'static,
+        F: Fn(Err) -> NewErr + Send + Sync + 'static,
+    {
+        MapErr {
+            handler: self,
+            mapper,
+        }
+    }
+
+    /// Supplies `state` to a handler of [`Stateful`] input.
+    ///
+    /// The resulting handler accepts the bare event and hands each call a
+    /// clone of the state alongside it.
+    fn with_state<E, S>(self, state: S) -> WithState<Self, S>
+    where
+        Self: Sized + Handler<Stateful<E, S>>,
+        E: Message,
+        S: Clone + Send + Sync + 'static,
+    {
+        WithState {
+            handler: self,
+            state,
+        }
+    }
+
+    /// Boxes the handler.
+    fn boxed(self) -> BoxHandler<In, Self::Output>
+    where
+        Self: Sized,
+    {
+        BoxHandler::new(self)
+    }
 }
 
 // Blanket impl for closures
@@ -40,7 +112,11 @@ where
 /// Dynamic object-safe handler.
 pub trait DynHandler<In: Message>: Send + Sync + 'static {
     type Output: HandlerResult;
-    fn call_dyn<'a>(&'a self, input: In) -> Pin<Box<dyn Future<Output = Self::Output> + Send + 'a>>;
+    fn call_dyn<'a>(&'a self, input: In)
+    -> Pin<Box<dyn Future<Output = Self::Output> + Send + 'a>>;
+
+    /// The name of this handler (dynamic dispatch version of [`Handler::name`]).
+    fn name_dyn(&self) -> Cow<'static, str>;
 }
 
 impl<H, In> DynHandler<In> for H
@@ -49,7 +125,174 @@ where
     In: Message,
 {
     type Output = H::Output;
-    fn call_dyn<'a>(&'a self, input: In) -> Pin<Box<dyn Future<Output = Self::Output> + Send + 'a>> {
+    fn call_dyn<'a>(
+        &'a self,
+        input: In,
+    ) -> Pin<Box<dyn Future<Output = Self::Output> + Send + 'a>> {
         Box::pin(self.call(input))
     }
+
+    fn name_dyn(&self) -> Cow<'static, str> {
+        self.name()
+    }
+}
+
+/// A handler whose output is transformed, created with [`Handler::map_output`].
+#[derive(Clone, Copy)]
+pub struct MapOutput<H, F> {
+    handler: H,
+    mapper: F,
+}
+
+impl<H, F, In, Out> Handler<In> for MapOutput<H, F>
+where
+    In: Message,
+    H: Handler<In>,
+    Out: HandlerResult,
+    F: Fn(H::Output) -> Out + Send + Sync + 'static,
+{
+    type Output = Out;
+
+    async fn call(&self, input: In) -> Self::Output {
+        (self.mapper)(self.handler.call(input).await)
+    }
+
+    fn name(&self) -> Cow<'static, str> {
+        self.handler.name()
+    }
+}
+
+/// Two handlers run one after the other, created with [`Handler::and_then`].
+#[derive(Clone, Copy)]
+pub struct AndThen<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B, In> Handler<In> for AndThen<A, B>
+where
+    In: Message,
+    A: Handler<In>,
+    A::Output: Message,
+    B: Handler<A::Output>,
+{
+    type Output = B::Output;
+
+    async fn call(&self, input: In) -> Self::Output {
+        let intermediate = self.first.call(input).await;
+        self.second.call(intermediate).await
+    }
+
+    fn name(&self) -> Cow<'static, str> {
+        self.first.name()
+    }
+}
+
+/// A handler whose error is transformed, created with [`Handler::map_err`].
+#[derive(Clone, Copy)]
+pub struct MapErr<H, F> {
+    handler: H,
+    mapper: F,
+}
+
+impl<H, F, In, T, Err, NewErr> Handler<In> for MapErr<H, F>
+where
+    In: Message,
+    H: Handler<In, Output = Result<T, Err>>,
+    T: Send + Sync + 'static,
+    NewErr: Send + Sync + 'static,
+    F: Fn(Err) -> NewErr + Send + Sync + 'static,
+{
+    type Output = Result<T, NewErr>;
+
+    async fn call(&self, input: In) -> Self::Output {
+        self.handler.call(input).await.map_err(&self.mapper)
+    }
+
+    fn name(&self) -> Cow<'static, str> {
+        self.handler.name()
+    }
+}
+
+/// An event paired with the state supplied by [`Handler::with_state`].
+#[derive(Clone, Debug)]
+pub struct Stateful<E, S> {
+    /// The event being handled.
+    pub event: E,
+    /// A clone of the handler's state.
+    pub state: S,
+}
+
+impl<E: Message, S: Send + Sync + 'static> Message for Stateful<E, S> {
+    fn variant(&self) -> Option<&'static str> {
+        self.event.variant()
+    }
+}
+
+/// A handler carrying its own state, created with [`Handler::with_state`].
+#[derive(Clone, Copy)]
+pub struct WithState<H, S> {
+    handler: H,
+    state: S,
+}
+
+impl<H, E, S> Handler<E> for WithState<H, S>
+where
+    E: Message,
+    S: Clone + Send + Sync + 'static,
+    H: Handler<Stateful<E, S>>,
+{
+    type Output = H::Output;
+
+    async fn call(&self, event: E) -> Self::Output {
+        let input = Stateful {
+            event,
+            state: self.state.clone(),
+        };
+        self.handler.call(input).await
+    }
+
+    fn name(&self) -> Cow<'static, str> {
+        self.handler.name()
+    }
+}
+
+/// An owned, type-erased handler.
+///
+/// Use this to store heterogeneous handlers with the same input and output
+/// types in a collection.
+pub struct BoxHandler<In, Out> {
+    inner: Box<dyn DynHandler<In, Output = Out>>,
+}
+
+impl<In, Out> BoxHandler<In, Out>
+where
+    In: Message,
+    Out: HandlerResult,
+{
+    /// Boxes `handler`.
+    pub fn new<H>(handler: H) -> Self
+    where
+        H: Handler<In, Output = Out>,
+    {
+        Self {
+            inner: Box::new(handler),
+        }
+    }
+}
+
+impl<In, Out> Handler<In> for BoxHandler<In, Out>
+where
+    In: Message,
+    Out: HandlerResult,
+{
+    type Output = Out;
+
+    fn call(&self, input: In) -> impl Future<Output = Self::Output> + Send {
+        self.inner.call_dyn(input)
+    }
+
+    fn name(&self) -> Cow<'static, str> {
+        self.inner.name_dyn()
+    }
 }
@@ -21,7 +21,7 @@
 //! - Wrapping Listener + Handler pipelines for execution
 
 use crate::message::Message;
-use std::{future::Future, pin::Pin};
+use std::{borrow::Cow, future::Future, marker::PhantomData, pin::Pin, sync::Arc};
 
 /// Result of hook execution indicating whether to continue or stop propagation.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -68,6 +68,50 @@ pub trait Hook<E: Message>: Send + Sync + 'static {
         &self,
         event: &E,
     ) -> impl Future<Output = Result<HookResult, Box<dyn std::error::Error + Send + Sync>>> + Send;
+
+    /// The name of this hook in errors, logs and traces.
+    ///
+    /// Defaults to the type name; wrapper hooks report the name of the hook
+    /// they wrap.
+    fn name(&self) -> Cow<'static, str> {
+        Cow::Borrowed(std::any::type_name::<Self>())
+    }
+
+    /// Only runs this hook for events matching `predicate`.
+    ///
+    /// Events that do not match continue to the next hook.
+    fn when<F>(self, predicate: F) -> When<Self, F>
+    where
+        Self: Sized,
+        F: Fn(&E) -> bool + Send + Sync + 'static,
+    {
+        When {
+            hook: self,
+            predicate,
+        }
+    }
+
+    /// Adapts this hook to another event type by projecting each event to `E`.
+    fn contramap<F, Outer>(self, project: F) -> Contramap<Self, F, E>
+    where
+        Self: Sized,
+        Outer: Message,
+        F: Fn(&Outer) -> E + Send + Sync + 'static,
+    {
+        Contramap {
+            hook: self,
+            project,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Boxes the hook for dynamic dispatch.
+    fn boxed(self) -> Box<dyn DynHook<E>>
+    where
+        Self: Sized,
+    {
+        Box::new(self)
+    }
 }
 
 /// Dynamic object-safe version of [`Hook`].
@@ -85,6 +129,9 @@ pub trait DynHook<E: Message>: Send + Sync + 'static {
                 + 'a,
         >,
     >;
+
+    /// The name of this hook (dynamic dispatch version of [`Hook::name`]).
+    fn name_dyn(&self) -> Cow<'static, str>;
 }
 
 // Blanket implementation: Any type implementing Hook implements DynHook automatically.
@@ -101,6 +148,10 @@ impl<E: Message, T: Hook<E>> DynHook<E> for T {
     > {
         Box::pin(self.on_event(event))
     }
+
+    fn name_dyn(&self) -> Cow<'static, str> {
+        self.name()
+    }
 }
 
 // Allow Box<dyn DynHook> to be used where Hook is expected.
@@ -109,6 +160,168 @@ impl<E: Message> Hook<E> for Box<dyn DynHook<E>> {
         &self,
         event: &E,
     ) -> Result<HookResult, Box<dyn std::error::Error + Send + Sync>> {
-        self.on_event_dyn(event).await
+        // Dispatch through the trait object, not the blanket impl for `Box` itself
+        (**self).on_event_dyn(event).await
+    }
+
+    fn name(&self) -> Cow<'static, str> {
+        (**self).name_dyn()
+    }
+}
+
+impl<E: Message, H: Hook<E>> Hook<E> for Arc<H> {
+    async fn on_event(
+        &self,
+        event: &E,
+    ) -> Result<HookResult, Box<dyn std::error::Error + Send + Sync>> {
+        (**self).on_event(event).await
+    }
+
+    fn name(&self) -> Cow<'static, str> {
+        (**self).name()
+    }
+}
+
+// An absent hook lets every event through.
+impl<E: Message, H: Hook<E>> Hook<E> for Option<H> {
+    async fn on_event(
+        &self,
+        event: &E,
+    ) -> Result<HookResult, Box<dyn std::error::Error + Send + Sync>> {
+        match self {
+            Some(hook) => hook.on_event(event).await,
+            None => Ok(HookResult::Next),
+        }
+    }
+
+    fn name(&self) -> Cow<'static, str> {
+        match self {
+            Some(hook) => hook.name(),
+            None => Cow::Borrowed(std::any::type_name::<Self>()),
+        }
+    }
+}
+
+// Runs the hooks in order until one stops propagation.
+impl<E: Message> Hook<E> for Vec<Box<dyn DynHook<E>>> {
+    async fn on_event(
+        &self,
+        event: &E,
+    ) -> Result<HookResult, Box<dyn std::error::Error + Send + Sync>> {
+        for hook in self {
+            if (**hook).on_event_dyn(event).await? == HookResult::Stop {
+                return Ok(HookResult::Stop);
+            }
+        }
+        Ok(HookResult::Next)
+    }
+}
+
+/// A hook backed by a closure, created with [`hook_fn`].
+#[derive(Clone, Copy)]
+pub struct HookFn<F> {
+    f: F,
+}
+
+/// Creates a hook from a closure returning a future.
+///
+/// The future cannot borrow the event; copy what it needs out of the event
+/// before the `async move` block.
+///
+/// ```rust,ignore
+/// let hook = hook_fn(|event: &Message| {
+///     let content = event.content.clone();
+///     async move {
+///         println!("{content}");
+///         Ok(HookResult::Next)
+///     }
+/// });
+/// ```
+pub fn hook_fn<E, F, Fut>(f: F) -> HookFn<F>
+where
+    E: Message,
+    F: Fn(&E) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<HookResult, Box<dyn std::error::Error + Send + Sync>>> + Send,
+{
+    HookFn { f }
+}
+
+impl<E, F, Fut> Hook<E> for HookFn<F>
+where
+    E: Message,
+    F: Fn(&E) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<HookResult, Box<dyn std::error::Error + Send + Sync>>> + Send,
+{
+    async fn on_event(
+        &self,
+        event: &E,
+    ) -> Result<HookResult, Box<dyn std::error::Error + Send + Sync>> {
+        (self.f)(event).await
+    }
+}
+
+/// A hook that only runs for matching events, created with [`Hook::when`].
+#[derive(Clone, Copy)]
+pub struct When<H, F> {
+    hook: H,
+    predicate: F,
+}
+
+impl<E, H, F> Hook<E> for When<H, F>
+where
+    E: Message,
+    H: Hook<E>,
+    F: Fn(&E) -> bool + Send + Sync + 'static,
+{
+    async fn on_event(
+        &self,
+        event: &E,
+    ) -> Result<HookResult, Box<dyn std::error::Error + Send + Sync>> {
+        if (self.predicate)(event) {
+            self.hook.on_event(event).await
+        } else {
+            Ok(HookResult::Next)
+        }
+    }
+
+    fn name(&self) -> Cow<'static, str> {
+        self.hook.name()
+    }
+}
+
+/// A hook adapted to another event type, created with [`Hook::contramap`].
+pub struct Contramap<H, F, E> {
+    hook: H,
+    project: F,
+    _marker: PhantomData<fn() -> E>,
+}
+
+impl<H: Clone, F: Clone, E> Clone for Contramap<H, F, E> {
+    fn clone(&self) -> Self {
+        Self {
+            hook: self.hook.clone(),
+            project: self.project.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<Outer, H, F, E> Hook<Outer> for Contramap<H, F, E>
+where
+    Outer: Message,
+    E: Message,
+    H: Hook<E>,
+    F: Fn(&Outer) -> E + Send + Sync + 'static,
+{
+    async fn on_event(
+        &self,
+        event: &Outer,
+    ) -> Result<HookResult, Box<dyn std::error::Error + Send + Sync>> {
+        let projected = (self.project)(event);
+        self.hook.on_event(&projected).await
+    }
+
+    fn name(&self) -> Cow<'static, str> {
+        self.hook.name()
     }
 }
@@ -58,6 +58,7 @@
 
 mod borrowed;
 mod context;
+mod envelope;
 mod error;
 mod handler;
 mod hook;
@@ -66,21 +67,36 @@ mod message;
 mod response;
 mod router;
 mod shared;
+mod trace;
 
 // Re-exports
-pub use borrowed::{BorrowedChain, BorrowedListener, RawMessage};
+pub use borrowed::{
+    BorrowedChain, BorrowedFilter, BorrowedListener, BorrowedMap, BorrowedMapFn, BorrowedPipeline,
+    RawMessage,
+};
 pub use context::{
-    AsyncFromEvent, BorrowedExtractHandler, Event, ExtractError, ExtractHandler, FromEvent,
-    FromEventGat, RefEvent, SyncExtractHandler,
+    AsyncFromEvent, BorrowedExtractHandler, BorrowedFn, Event, ExtractError, ExtractHandler,
+    FromEvent, FromEventGat, Owned, RefEvent, SyncBorrowedExtractHandler, SyncExtractHandler,
 };
 
+pub use envelope::{
+    Correlation, Envelope, EventId, Headers, Metadata, ParseEventIdError, Timestamp,
+};
 pub use error::{BoxError, HookError, RistenError, RoutingError};
-pub use handler::{DynHandler, Handler, HandlerResult};
-pub use hook::{DynHook, Hook, HookResult};
+pub use handler::{
+    AndThen, BoxHandler, DynHandler, Handler, HandlerResult, MapErr, MapOutput, Stateful, WithState,
+};
+pub use hook::{Contramap, DynHook, Hook, HookFn, HookResult, When, hook_fn};
 pub use listener::{
     BoxListener, Catch, Chain, DynListener, Filter, FilterMap, Listener, Map, Pipeline, Then,
 };
-pub use message::Message;
+#[doc(hidden)]
+pub use message::DerivedVariants;
+pub use message::{Message, MessageMeta, VariantMessage};
 pub use response::{Continue, Handled, IntoHookOutcome, IntoResponse};
-pub use router::{DynRouter, ExecutionStrategy, RouteResult, Router, RouterHook};
+pub use router::{
+    BoxRouter, DynRouter, ExecutionStrategy, FallbackRouter, MapErrRouter, MapEventRouter,
+    RouteResult, Router, RouterExt, RouterHook, ThenRouter,
+};
 pub use shared::SharedEvent;
+pub use trace::{TRACEPARENT, TRACESTATE, TraceContext};
@@ -20,7 +20,7 @@
 //! [`Router`]: crate::Router
 
 use crate::{error::BoxError, handler::Handler, message::Message};
-use std::{future::Future, pin::Pin};
+use std::{borrow::Cow, future::Future, pin::Pin};
 
 /// A domain gateway that interprets events.
 #[diagnostic::on_unimplemented(
@@ -36,6 +36,13 @@ pub trait Listener<In: Message>: Send + Sync + 'static {
         event: &In,
     ) -> impl Future<Output = Result<Option<Self::Output>, BoxError>> + Send;
 
+    /// The name of this listener in errors, logs and traces.
+    ///
+    /// Defaults to the type name.
+    fn name(&self) -> Cow<'static, str> {
+        Cow::Borrowed(std::any::type_name::<Self>())
+    }
+
     /// Chains this listener with another listener.
     fn and_then<Next>(self, next: Next) -> Chain<Self, Next>
     where
@@ -61,7 +68,7 @@ pub trait Listener<In: Message>: Send + Sync + 'static {
     }
 
     /// Transforms the output of this listener (sync).
-    fn map<F, Out>(self, mapper: F) -> Map<Self, F>
+    fn map<F, Out>(self, mapper: F) -> Map<Self, F, Out>
     where
         Self: Sized,
         Out: Message,
@@ -75,7 +82,7 @@ pub trait Listener<In: Message>: Send + Sync + 'static {
     }
 
     /// Transforms the output of this listener (async).
-    fn then<F, Out, Fut>(self, mapper: F) -> Then<Self, F>
+    fn then<F, Out, Fut>(self, mapper: F) -> Then<Self, F, Out>
     where
         Self: Sized,
         Out: Message,
@@ -90,7 +97,7 @@ pub trait Listener<In: Message>: Send + Sync + 'static {
     }
 
     /// Filters and maps in one step.
-    fn filter_map<F, Out>(self, mapper: F) -> FilterMap<Self, F>
+    fn filter_map<F, Out>(self, mapper: F) -> FilterMap<Self, F, Out>
     where
         Self: Sized,
         Out: Message,
@@ -182,6 +189,10 @@ where
     }
 }
 
+/// Listener returned by [`Listener::map`], producing `Out`.
+///
+/// `Out` defaults to `()`, so `Map<L, F>` still names listeners whose
+/// mapper returns `()`.
 pub struct Map<L, F, Out = ()> {
     listener: L,
     mapper: F,
@@ -206,6 +217,10 @@ where
     }
 }
 
+/// Listener returned by [`Listener::then`], producing `Out`.
+///
+/// `Out` defaults to `()`, so `Then<L, F>` still names listeners whose
+/// mapper's future resolves to `()`.
 pub struct Then<L, F, Out = ()> {
     listener: L,
     mapper: F,
@@ -231,6 +246,10 @@ where
     }
 }
 
+/// Listener returned by [`Listener::filter_map`], producing `Out`.
+///
+/// `Out` defaults to `()`, so `FilterMap<L, F>` still names listeners whose
+/// mapper returns `()`.
 pub struct FilterMap<L, F, Out = ()> {
     listener: L,
     mapper: F,
@@ -287,6 +306,11 @@ where
             Err(e) => Err(e),
         }
     }
+
+    /// Pipelines are named after their handler.
+    fn name(&self) -> Cow<'static, str> {
+        self.handler.name()
+    }
 }
 
 pub struct BoxListener<In, Out> {
@@ -319,6 +343,10 @@ where
     async fn listen(&self, event: &In) -> Result<Option<Self::Output>, BoxError> {
         self.inner.listen_dyn(event).await
     }
+
+    fn name(&self) -> Cow<'static, str> {
+        self.inner.name_dyn()
+    }
 }
 
 pub trait DynListener<In>: Send + Sync + 'static {
@@ -327,6 +355,9 @@ pub trait DynListener<In>: Send + Sync + 'static {
         &'a self,
         event: &'a In,
     ) -> Pin<Box<dyn Future<Output = Result<Option<Self::Output>, BoxError>> + Send + 'a>>;
+
+    /// The name of this listener (dynamic dispatch version of [`Listener::name`]).
+    fn name_dyn(&self) -> Cow<'static, str>;
 }
 
 impl<L, In> DynListener<In> for L
@@ -342,6 +373,10 @@ where
     ) -> Pin<Box<dyn Future<Output = Result<Option<Self::Output>, BoxError>> + Send + 'a>> {
         Box::pin(self.listen(event))
     }
+
+    fn name_dyn(&self) -> Cow<'static, str> {
+        self.name()
+    }
 }
 
 pub struct Catch<L, F> {
@@ -370,4 +405,8 @@ where
             Err(e) => Ok((self.handler)(e)),
         }
     }
+
+    fn name(&self) -> Cow<'static, str> {
+        self.listener.name()
+    }
 }
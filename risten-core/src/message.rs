@@ -1,5 +1,7 @@
 //! Message trait for event types.
 
+use crate::envelope::Metadata;
+
 /// A marker trait for events and triggers within the system.
 ///
 /// Messages must be `Send + Sync + 'static` to be safe for async use.
@@ -17,7 +19,77 @@
     label = "must be `Send + Sync + 'static`",
     note = "All events in Risten must be thread-safe and static."
 )]
-pub trait Message: Send + Sync + 'static {}
+pub trait Message: Send + Sync + 'static {
+    /// The name of the enum variant this message holds, if it is an enum.
+    ///
+    /// `#[derive(Message)]` implements this for enums so that routers can
+    /// select variant-level subscribers without trying every handler.
+    fn variant(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// The standard metadata of this message, if it carries any.
+    ///
+    /// [`Envelope`](crate::Envelope) implements this; bare events have none.
+    fn metadata(&self) -> Option<&Metadata> {
+        None
+    }
+
+    /// Mutable access to the standard metadata of this message, if it carries any.
+    fn metadata_mut(&mut self) -> Option<&mut Metadata> {
+        None
+    }
+}
+
+/// An enum [`Message`] reporting its variants through [`Message::variant`].
+///
+/// `#[derive(Message)]` implements this for enums, and
+/// `#[subscribe(Enum::Variant)]` requires it: with a hand-written `Message`
+/// impl, `variant` returns `None` and every variant would reach the subscriber.
+///
+/// The trait is sealed: only the derive can implement it.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not report its variants",
+    note = "derive `Message` on the enum to subscribe to its variants"
+)]
+pub trait VariantMessage: Message + DerivedVariants {}
+
+/// Seals [`VariantMessage`]. Implemented by `#[derive(Message)]`; not public API.
+#[doc(hidden)]
+pub trait DerivedVariants {}
+
+/// Stable metadata describing a message type.
+///
+/// Keyed routers, logging, tracing spans and serialization envelopes use this
+/// instead of [`std::any::type_name`], which is neither stable nor meant for
+/// display. `#[derive(Message)]` implements it, configured with
+/// `#[message(name = "...", topic = "...", key = field)]`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[derive(Message)]
+/// #[message(name = "chat.message", topic = "chat", key = channel_id)]
+/// struct MessageEvent {
+///     channel_id: u64,
+///     content: String,
+/// }
+///
+/// assert_eq!(MessageEvent::NAME, "chat.message");
+/// assert_eq!(event.key().as_deref(), Some("42"));
+/// ```
+pub trait MessageMeta: Message {
+    /// The stable name of this message, e.g. `"chat.message"`.
+    const NAME: &'static str;
+
+    /// The topic this message belongs to, if any.
+    const TOPIC: Option<&'static str> = None;
+
+    /// The partition or routing key of this message, if any.
+    fn key(&self) -> Option<String> {
+        None
+    }
+}
 
 // Common Message implementations
 impl Message for () {}
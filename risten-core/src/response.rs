@@ -19,7 +19,7 @@
 //! }
 //! ```
 
-use crate::hook::HookResult;
+use crate::{context::ExtractError, error::BoxError, hook::HookResult};
 
 /// Trait for converting a handler's output into a [`HookResult`].
 ///
@@ -35,7 +35,7 @@ use crate::hook::HookResult;
 /// | [`HookResult`] | As is |
 /// | [`Handled`] | Stop propagation |
 /// | [`Continue<T>`] | Next (continue propagation) |
-/// | `Result<T, E>` | Delegates to `T` or propagates error |
+/// | `Result<T, E>` | Delegates to `T` or propagates error (soft [`ExtractError`] rejections resolve to their outcome) |
 /// | `Option<T>` | `Some(t)` delegates, `None` = Next |
 /// | `String` / `&str` | Next (informational output) |
 /// | Numeric types | Next (status codes, counts) |
@@ -125,7 +125,11 @@ where
     fn into_response(self) -> Result<HookResult, Box<dyn std::error::Error + Send + Sync>> {
         match self {
             Ok(t) => t.into_response(),
-            Err(e) => Err(Box::new(e)),
+            // Soft extractor rejections resolve to their outcome, not an error.
+            Err(e) => match (Box::new(e) as BoxError).downcast::<ExtractError>() {
+                Ok(rejection) => rejection.into_response(),
+                Err(e) => Err(e),
+            },
         }
     }
 }
@@ -163,4 +167,6 @@ macro_rules! impl_into_response_for_numeric {
     };
 }
 
-impl_into_response_for_numeric!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+impl_into_response_for_numeric!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64
+);
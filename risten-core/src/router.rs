@@ -41,11 +41,11 @@
 //! ```
 
 use crate::{
-    error::BoxError,
+    error::{BoxError, RistenError},
     hook::{Hook, HookResult},
     message::Message,
 };
-use std::{future::Future, pin::Pin};
+use std::{future::Future, marker::PhantomData, pin::Pin};
 
 /// The result of a routing operation.
 ///
@@ -267,3 +267,204 @@ where
         }
     }
 }
+
+/// Combinators for composing [`Router`]s.
+///
+/// Implemented for every router. Routers combined with [`then`](Self::then)
+/// or [`fallback`](Self::fallback) must share an error type; align them with
+/// [`map_err`](Self::map_err) or [`boxed`](Self::boxed).
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let router = commands
+///     .fallback(chat)
+///     .then(audit)
+///     .boxed();
+/// ```
+pub trait RouterExt<E: Message>: Router<E> + Sized {
+    /// Routes the event through this router, then through `other`.
+    fn then<R>(self, other: R) -> ThenRouter<Self, R>
+    where
+        R: Router<E, Error = Self::Error>,
+    {
+        ThenRouter {
+            first: self,
+            second: other,
+        }
+    }
+
+    /// Routes the event through `other` only if this router did not stop it.
+    fn fallback<R>(self, other: R) -> FallbackRouter<Self, R>
+    where
+        R: Router<E, Error = Self::Error>,
+    {
+        FallbackRouter {
+            primary: self,
+            fallback: other,
+        }
+    }
+
+    /// Adapts this router to an outer event type by projecting each event to `E`.
+    fn map_event<F, Outer>(self, project: F) -> MapEventRouter<Self, F, E>
+    where
+        Outer: Message,
+        F: Fn(&Outer) -> E + Send + Sync,
+    {
+        MapEventRouter {
+            router: self,
+            project,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Transforms the error of this router.
+    fn map_err<F, Err>(self, mapper: F) -> MapErrRouter<Self, F>
+    where
+        Err: std::error::Error + Send + Sync + 'static,
+        F: Fn(Self::Error) -> Err + Send + Sync,
+    {
+        MapErrRouter {
+            router: self,
+            mapper,
+        }
+    }
+
+    /// Erases the router's type and boxes its error.
+    fn boxed(self) -> BoxRouter<E>
+    where
+        Self: 'static,
+    {
+        BoxRouter::new(self)
+    }
+}
+
+impl<E: Message, R: Router<E>> RouterExt<E> for R {}
+
+/// Two routers run one after the other, created with [`RouterExt::then`].
+#[derive(Debug, Clone, Copy)]
+pub struct ThenRouter<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<E, A, B> Router<E> for ThenRouter<A, B>
+where
+    E: Message,
+    A: Router<E>,
+    B: Router<E, Error = A::Error>,
+{
+    type Error = A::Error;
+
+    async fn route(&self, event: &E) -> Result<RouteResult, Self::Error> {
+        let first = self.first.route(event).await?;
+        let second = self.second.route(event).await?;
+        Ok(first.merge(second))
+    }
+}
+
+/// A router with a fallback, created with [`RouterExt::fallback`].
+#[derive(Debug, Clone, Copy)]
+pub struct FallbackRouter<A, B> {
+    primary: A,
+    fallback: B,
+}
+
+impl<E, A, B> Router<E> for FallbackRouter<A, B>
+where
+    E: Message,
+    A: Router<E>,
+    B: Router<E, Error = A::Error>,
+{
+    type Error = A::Error;
+
+    async fn route(&self, event: &E) -> Result<RouteResult, Self::Error> {
+        let primary = self.primary.route(event).await?;
+        if primary.stopped {
+            return Ok(primary);
+        }
+        let fallback = self.fallback.route(event).await?;
+        Ok(primary.merge(fallback))
+    }
+}
+
+/// A router adapted to another event type, created with [`RouterExt::map_event`].
+pub struct MapEventRouter<R, F, E> {
+    router: R,
+    project: F,
+    _marker: PhantomData<fn() -> E>,
+}
+
+impl<R: Clone, F: Clone, E> Clone for MapEventRouter<R, F, E> {
+    fn clone(&self) -> Self {
+        Self {
+            router: self.router.clone(),
+            project: self.project.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<Outer, R, F, E> Router<Outer> for MapEventRouter<R, F, E>
+where
+    Outer: Message,
+    E: Message,
+    R: Router<E>,
+    F: Fn(&Outer) -> E + Send + Sync,
+{
+    type Error = R::Error;
+
+    async fn route(&self, event: &Outer) -> Result<RouteResult, Self::Error> {
+        let projected = (self.project)(event);
+        self.router.route(&projected).await
+    }
+}
+
+/// A router whose error is transformed, created with [`RouterExt::map_err`].
+#[derive(Debug, Clone, Copy)]
+pub struct MapErrRouter<R, F> {
+    router: R,
+    mapper: F,
+}
+
+impl<E, R, F, Err> Router<E> for MapErrRouter<R, F>
+where
+    E: Message,
+    R: Router<E>,
+    Err: std::error::Error + Send + Sync + 'static,
+    F: Fn(R::Error) -> Err + Send + Sync,
+{
+    type Error = Err;
+
+    async fn route(&self, event: &E) -> Result<RouteResult, Self::Error> {
+        self.router.route(event).await.map_err(&self.mapper)
+    }
+}
+
+/// An owned, type-erased router.
+///
+/// The router's error is boxed into [`RistenError::Custom`], so routers with
+/// different error types can be stored together or combined.
+pub struct BoxRouter<E> {
+    inner: Box<dyn DynRouter<E, Error = RistenError>>,
+}
+
+impl<E: Message> BoxRouter<E> {
+    /// Boxes `router`.
+    pub fn new<R>(router: R) -> Self
+    where
+        R: Router<E> + 'static,
+    {
+        Self {
+            inner: Box::new(router.map_err(|err| RistenError::Custom(Box::new(err)))),
+        }
+    }
+}
+
+impl<E: Message> Router<E> for BoxRouter<E> {
+    type Error = RistenError;
+
+    async fn route(&self, event: &E) -> Result<RouteResult, Self::Error> {
+        DynRouter::route(&*self.inner, event).await
+    }
+}
@@ -0,0 +1,138 @@
+//! # W3C Trace Context
+//!
+//! A [`TraceContext`] identifies the span that produced an event, in the
+//! format of the [W3C Trace Context](https://www.w3.org/TR/trace-context/)
+//! `traceparent` and `tracestate` headers.
+//!
+//! Envelopes carry it as plain headers (see [`Metadata::trace_context`]), so
+//! it survives queues and serialization like any other header.
+//!
+//! [`Metadata::trace_context`]: crate::Metadata::trace_context
+
+use crate::envelope::EventId;
+use std::fmt;
+
+/// The header holding the `traceparent` of an event.
+pub const TRACEPARENT: &str = "traceparent";
+
+/// The header holding the `tracestate` of an event.
+pub const TRACESTATE: &str = "tracestate";
+
+/// The position of a span within a distributed trace.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TraceContext {
+    trace_id: u128,
+    span_id: u64,
+    flags: u8,
+    state: Option<String>,
+}
+
+impl TraceContext {
+    /// Whether the caller of the span recorded it.
+    pub const SAMPLED: u8 = 0x01;
+
+    /// Create a context from its ids.
+    ///
+    /// Returns `None` if either id is zero, which W3C reserves as invalid.
+    pub fn new(trace_id: u128, span_id: u64, flags: u8) -> Option<Self> {
+        (trace_id != 0 && span_id != 0).then_some(Self {
+            trace_id,
+            span_id,
+            flags,
+            state: None,
+        })
+    }
+
+    /// Parse the `traceparent` and `tracestate` header values.
+    ///
+    /// Returns `None` if `traceparent` is malformed; an empty `tracestate` is
+    /// ignored.
+    pub fn parse(traceparent: &str, tracestate: Option<&str>) -> Option<Self> {
+        let mut parts = traceparent.trim().splitn(5, '-');
+        let version = hex(parts.next()?, 2)?;
+        let trace_id = hex(parts.next()?, 32)?;
+        let span_id = hex(parts.next()?, 16)?;
+        let flags = hex(parts.next()?, 2)?;
+        // Version 00 has exactly four fields; later versions may append more
+        if version == 0xff || (version == 0 && parts.next().is_some()) {
+            return None;
+        }
+        let mut context = Self::new(trace_id, span_id as u64, flags as u8)?;
+        context.state = tracestate
+            .map(str::trim)
+            .filter(|state| !state.is_empty())
+            .map(str::to_owned);
+        Some(context)
+    }
+
+    /// The id of the whole trace.
+    pub fn trace_id(&self) -> u128 {
+        self.trace_id
+    }
+
+    /// The id of the span that produced the event.
+    pub fn span_id(&self) -> u64 {
+        self.span_id
+    }
+
+    /// The trace flags.
+    pub fn flags(&self) -> u8 {
+        self.flags
+    }
+
+    /// Whether the trace is sampled.
+    pub fn is_sampled(&self) -> bool {
+        self.flags & Self::SAMPLED != 0
+    }
+
+    /// The vendor-specific `tracestate`, if any.
+    pub fn tracestate(&self) -> Option<&str> {
+        self.state.as_deref()
+    }
+
+    /// Set the vendor-specific `tracestate`.
+    pub fn with_tracestate(mut self, state: impl Into<String>) -> Self {
+        self.state = Some(state.into());
+        self
+    }
+
+    /// A new span of the same trace, whose parent is this span.
+    pub fn child(&self) -> Self {
+        let span_id = loop {
+            let id = EventId::new().as_u128() as u64;
+            if id != 0 {
+                break id;
+            }
+        };
+        Self {
+            span_id,
+            ..self.clone()
+        }
+    }
+
+    /// The `traceparent` header value.
+    pub fn traceparent(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for TraceContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "00-{:032x}-{:016x}-{:02x}",
+            self.trace_id, self.span_id, self.flags
+        )
+    }
+}
+
+/// Parses exactly `len` lowercase hex digits.
+fn hex(digits: &str, len: usize) -> Option<u128> {
+    let valid = digits.len() == len
+        && digits
+            .bytes()
+            .all(|byte| byte.is_ascii_digit() || (b'a'..=b'f').contains(&byte));
+    valid
+        .then(|| u128::from_str_radix(digits, 16).ok())
+        .flatten()
+}
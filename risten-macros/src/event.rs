@@ -7,19 +7,119 @@
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::{
-    DeriveInput, Expr, FnArg, Ident, ItemFn, LitInt, LitStr, Token, Type,
+    Attribute, Data, DeriveInput, Expr, Fields, FnArg, Ident, ItemFn, LitInt, LitStr, Member,
+    Token, Type,
     parse::{Parse, ParseStream},
     parse_macro_input,
 };
 
+/// Arguments of the `#[message(...)]` attribute on `#[derive(Message)]`.
+#[derive(Default)]
+pub(crate) struct MessageArgs {
+    pub name: Option<LitStr>,
+    pub topic: Option<LitStr>,
+    pub key: Option<Member>,
+}
+
+impl MessageArgs {
+    fn from_attrs(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut args = MessageArgs::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("message")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("name") {
+                    args.name = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("topic") {
+                    args.topic = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("key") {
+                    args.key = Some(meta.value()?.parse()?);
+                } else {
+                    return Err(meta.error("unknown attribute: expected `name`, `topic` or `key`"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(args)
+    }
+}
+
 /// Derive macro for implementing `Message` trait.
+///
+/// Also implements `MessageMeta`, configured with `#[message(...)]`.
 pub fn derive_message_impl(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let name = &input.ident;
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
+    let args = match MessageArgs::from_attrs(&input.attrs) {
+        Ok(args) => args,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    if let (Some(key), Data::Enum(_)) = (&args.key, &input.data) {
+        return syn::Error::new_spanned(key, "`key` is only supported on structs")
+            .to_compile_error()
+            .into();
+    }
+
+    // Enums report their variant so routers can dispatch by discriminant
+    let variant_fn = match &input.data {
+        Data::Enum(data_enum) if !data_enum.variants.is_empty() => {
+            let arms = data_enum.variants.iter().map(|variant| {
+                let variant_name = &variant.ident;
+                let pattern = match &variant.fields {
+                    Fields::Unnamed(_) => quote! { Self::#variant_name(..) },
+                    Fields::Named(_) => quote! { Self::#variant_name { .. } },
+                    Fields::Unit => quote! { Self::#variant_name },
+                };
+                quote! { #pattern => stringify!(#variant_name) }
+            });
+            Some(quote! {
+                fn variant(&self) -> ::core::option::Option<&'static str> {
+                    ::core::option::Option::Some(match self {
+                        #(#arms),*
+                    })
+                }
+            })
+        }
+        _ => None,
+    };
+
+    let variant_impl = variant_fn.is_some().then(|| {
+        quote! {
+            impl #impl_generics ::risten::__private::DerivedVariants for #name #ty_generics #where_clause {}
+            impl #impl_generics ::risten::VariantMessage for #name #ty_generics #where_clause {}
+        }
+    });
+
+    // The stable name defaults to the type's name, without its module path
+    let event_name = match &args.name {
+        Some(lit) => quote! { #lit },
+        None => quote! { stringify!(#name) },
+    };
+    let topic = args.topic.as_ref().map(|lit| {
+        quote! {
+            const TOPIC: ::core::option::Option<&'static str> = ::core::option::Option::Some(#lit);
+        }
+    });
+    let key_fn = args.key.as_ref().map(|member| {
+        quote! {
+            fn key(&self) -> ::core::option::Option<::std::string::String> {
+                ::core::option::Option::Some(::std::string::ToString::to_string(&self.#member))
+            }
+        }
+    });
+
     let expanded = quote! {
-        impl #impl_generics ::risten::Message for #name #ty_generics #where_clause {}
+        impl #impl_generics ::risten::Message for #name #ty_generics #where_clause {
+            #variant_fn
+        }
+
+        impl #impl_generics ::risten::MessageMeta for #name #ty_generics #where_clause {
+            const NAME: &'static str = #event_name;
+            #topic
+            #key_fn
+        }
+
+        #variant_impl
     };
 
     TokenStream::from(expanded)
@@ -154,6 +254,10 @@ pub fn event_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
                 #filter_check
                 #fn_block
             }
+
+            fn name(&self) -> ::std::borrow::Cow<'static, str> {
+                ::std::borrow::Cow::Borrowed(stringify!(#fn_name))
+            }
         }
     };
 
@@ -0,0 +1,158 @@
+//! Extraction-related macros.
+//!
+//! This module contains:
+//! - `#[derive(FromEvent)]` - Derive macro generating field projection extractors
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{
+    Data, DeriveInput, Fields, Ident, Index, Member, Token, Type, parse::Parse, parse_macro_input,
+};
+
+/// Arguments of an `#[extract(...)]` field attribute.
+pub(crate) struct ExtractArgs {
+    /// Name of the generated extractor newtype.
+    pub name: Ident,
+    /// Whether to implement `FromEventGat`, and the borrowed target type, if given.
+    pub borrow: Option<Option<Type>>,
+}
+
+impl Parse for ExtractArgs {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let name: Ident = input.parse()?;
+        let mut borrow = None;
+
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+
+            let ident: Ident = input.parse()?;
+            match ident.to_string().as_str() {
+                "borrow" => {
+                    if input.peek(Token![=]) {
+                        input.parse::<Token![=]>()?;
+                        borrow = Some(Some(input.parse()?));
+                    } else {
+                        borrow = Some(None);
+                    }
+                }
+                other => {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        format!("unknown attribute: {}", other),
+                    ));
+                }
+            }
+        }
+
+        Ok(ExtractArgs { name, borrow })
+    }
+}
+
+/// Implementation of `#[derive(FromEvent)]`.
+pub fn derive_from_event_impl(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match expand_from_event(&input) {
+        Ok(expanded) => TokenStream::from(expanded),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand_from_event(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let event_name = &input.ident;
+    let vis = &input.vis;
+
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "#[derive(FromEvent)] does not support generic events",
+        ));
+    }
+
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                input,
+                "#[derive(FromEvent)] can only be used on structs",
+            ));
+        }
+    };
+
+    let members: Vec<(Member, &syn::Field)> = match fields {
+        Fields::Named(named) => named
+            .named
+            .iter()
+            .map(|field| (Member::Named(field.ident.clone().unwrap()), field))
+            .collect(),
+        Fields::Unnamed(unnamed) => unnamed
+            .unnamed
+            .iter()
+            .enumerate()
+            .map(|(i, field)| (Member::Unnamed(Index::from(i)), field))
+            .collect(),
+        Fields::Unit => Vec::new(),
+    };
+
+    let mut expanded = Vec::new();
+    for (member, field) in members {
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("extract"))
+        {
+            let args: ExtractArgs = attr.parse_args()?;
+            let name = &args.name;
+            let ty = &field.ty;
+
+            expanded.push(quote! {
+                #[doc = concat!("Extracts `", stringify!(#event_name), "::", stringify!(#member), "`.")]
+                #[derive(Clone)]
+                #vis struct #name(pub #ty);
+
+                impl ::core::ops::Deref for #name {
+                    type Target = #ty;
+
+                    fn deref(&self) -> &Self::Target {
+                        &self.0
+                    }
+                }
+
+                impl ::risten::FromEvent<#event_name> for #name {
+                    type Error = ::core::convert::Infallible;
+
+                    fn from_event(event: &#event_name) -> ::core::result::Result<Self, Self::Error> {
+                        ::core::result::Result::Ok(Self(::core::clone::Clone::clone(&event.#member)))
+                    }
+                }
+            });
+
+            // Borrowed projections avoid the clone: `&'a Field`, or `&'a T` via `AsRef<T>`
+            let borrowed = match &args.borrow {
+                None => None,
+                Some(None) => Some((quote! { #ty }, quote! { &event.#member })),
+                Some(Some(target)) => Some((
+                    quote! { #target },
+                    quote! { ::core::convert::AsRef::<#target>::as_ref(&event.#member) },
+                )),
+            };
+            if let Some((target, projection)) = borrowed {
+                expanded.push(quote! {
+                    impl ::risten::FromEventGat<#event_name> for #name {
+                        type Output<'a> = &'a #target;
+                        type Error = ::core::convert::Infallible;
+
+                        fn extract(event: &#event_name) -> ::core::result::Result<&#target, Self::Error> {
+                            ::core::result::Result::Ok(#projection)
+                        }
+                    }
+                });
+            }
+        }
+    }
+
+    Ok(quote! { #(#expanded)* })
+}
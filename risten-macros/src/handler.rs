@@ -3,21 +3,35 @@
 //! This module contains:
 //! - `#[handler]` - Attribute macro for creating Handler implementations with extraction
 
+use crate::subscribe::{extraction_binding, extractor_attr, referenced_type};
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{FnArg, Ident, ItemFn, LitInt, LitStr, Token, Type, parse::Parse, parse_macro_input};
+use syn::{
+    FnArg, Ident, ItemFn, LitInt, LitStr, ReturnType, Token, Type, parse::Parse, parse_macro_input,
+};
 
 /// Arguments for the `#[handler]` macro.
 pub(crate) struct HandlerArgs {
+    /// Optional explicit input type; all arguments are then extracted from it.
+    pub input_type: Option<Type>,
     pub name: Option<String>,
     pub priority: Option<i32>,
 }
 
 impl Parse for HandlerArgs {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut input_type = None;
         let mut name = None;
         let mut priority = None;
 
+        // A leading bare type is the explicit input type
+        if !input.is_empty() && (!input.peek(Ident) || !input.peek2(Token![=])) {
+            input_type = Some(input.parse()?);
+            if input.peek(Token![,]) {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
         while !input.is_empty() {
             let ident: Ident = input.parse()?;
             input.parse::<Token![=]>()?;
@@ -44,24 +58,40 @@ impl Parse for HandlerArgs {
             }
         }
 
-        Ok(HandlerArgs { name, priority })
+        Ok(HandlerArgs {
+            input_type,
+            name,
+            priority,
+        })
     }
 }
 
 /// Implementation of the `#[handler]` macro.
+///
+/// A single argument `input: T` (or `input: &T`) makes a `Handler<T>` whose
+/// output is the function's return type. With more arguments, or an explicit
+/// input type, each argument is extracted from the input and the output is
+/// `Result<Ret, ExtractError>`.
 pub fn handler_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
     let args = parse_macro_input!(attr as HandlerArgs);
     let input = parse_macro_input!(item as ItemFn);
 
+    match expand_handler(args, &input) {
+        Ok(expanded) => TokenStream::from(expanded),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand_handler(args: HandlerArgs, input: &ItemFn) -> syn::Result<proc_macro2::TokenStream> {
     let fn_name = &input.sig.ident;
     let fn_vis = &input.vis;
     let fn_block = &input.block;
-
-    if input.sig.asyncness.is_none() {
-        return syn::Error::new_spanned(&input.sig.fn_token, "Handler function must be async")
-            .to_compile_error()
-            .into();
-    }
+    let (doc_attrs, fn_attrs): (Vec<_>, Vec<_>) = input
+        .attrs
+        .iter()
+        .partition(|attr| attr.path().is_ident("doc"));
+    let inputs = &input.sig.inputs;
+    let is_async = input.sig.asyncness.is_some();
 
     let struct_name = if let Some(ref custom_name) = args.name {
         Ident::new(custom_name, fn_name.span())
@@ -69,84 +99,121 @@ pub fn handler_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
         fn_name.clone()
     };
 
-    let inputs = &input.sig.inputs;
-    if inputs.is_empty() {
-        return syn::Error::new_spanned(
-            inputs,
-            "Handler function must have at least one argument (the event)",
-        )
-        .to_compile_error()
-        .into();
-    }
+    let ret_type = match &input.sig.output {
+        ReturnType::Default => quote! { () },
+        ReturnType::Type(_, ty) => quote! { #ty },
+    };
 
-    let event_type = match inputs.first() {
-        Some(FnArg::Typed(pat_type)) => {
-            if let Type::Reference(type_ref) = &*pat_type.ty {
-                &type_ref.elem
-            } else {
-                return syn::Error::new_spanned(
-                    &pat_type.ty,
-                    "Handler event argument must be a reference (&Event)",
-                )
-                .to_compile_error()
-                .into();
+    let mut arg_pats = Vec::new();
+    let mut arg_types = Vec::new();
+    let mut extractors = Vec::new();
+    for arg in inputs {
+        match arg {
+            FnArg::Typed(pat_type) => {
+                arg_pats.push(&pat_type.pat);
+                arg_types.push(&*pat_type.ty);
+                extractors.push(extractor_attr(pat_type)?);
+            }
+            FnArg::Receiver(receiver) => {
+                return Err(syn::Error::new_spanned(
+                    receiver,
+                    "Handler function cannot take a `self` parameter",
+                ));
             }
         }
-        _ => {
-            return syn::Error::new_spanned(
-                inputs,
-                "Handler function must take an event argument: fn(event: &Event, ...)",
-            )
-            .to_compile_error()
-            .into();
+    }
+
+    // The input type is explicit, or taken from the first argument (`T` or `&T`)
+    let input_type = match (&args.input_type, arg_types.first()) {
+        (Some(ty), _) => ty,
+        (None, Some(_)) if extractors[0].is_some() => {
+            return Err(syn::Error::new_spanned(
+                &inputs[0],
+                "Handler function starting with an #[extract] argument must name the input type: #[handler(Input)]",
+            ));
         }
+        (None, Some(ty)) => referenced_type(ty).unwrap_or(ty),
+        (None, None) => {
+            return Err(syn::Error::new_spanned(
+                &input.sig,
+                "Handler function must take an input argument, or name the input type: #[handler(Input)]",
+            ));
+        }
+    };
+    let extracting = args.input_type.is_some() || arg_types.len() > 1;
+
+    // The user function is kept intact so that `return` and `?` behave as written
+    let generics = &input.sig.generics;
+    let where_clause = &generics.where_clause;
+    let inner_fn = quote! {
+        #(#fn_attrs)*
+        fn __inner #generics (#(#arg_pats: #arg_types),*) -> #ret_type #where_clause
+            #fn_block
+    };
+    let inner_fn = if is_async {
+        quote! { async #inner_fn }
+    } else {
+        inner_fn
+    };
+    let await_inner = if is_async {
+        quote! { .await }
+    } else {
+        quote! {}
     };
 
-    let _arg_pats: Vec<_> = inputs.iter().collect();
-    let _arg_bindings: Vec<_> = inputs
-        .iter()
-        .enumerate()
-        .map(|(i, arg)| {
-            if let FnArg::Typed(pat_type) = arg {
-                let pat = &pat_type.pat;
-                quote! { #pat }
-            } else {
-                let ident = Ident::new(&format!("__arg{}", i), proc_macro2::Span::call_site());
-                quote! { #ident }
-            }
-        })
+    let input_type_str = quote!(#input_type).to_string();
+    let arg_names: Vec<_> = (0..arg_types.len())
+        .map(|i| Ident::new(&format!("__arg_{}", i), fn_name.span()))
         .collect();
 
-    let extractor_bindings: Vec<_> = inputs
-        .iter()
-        .skip(1)
-        .enumerate()
-        .filter_map(|(i, arg)| {
-            if let FnArg::Typed(pat_type) = arg {
-                let pat = &pat_type.pat;
-                let ty = &pat_type.ty;
-                let _idx = i + 1;
-                Some(quote! {
-                    let #pat: #ty = match <#ty as ::risten::FromEvent<#event_type>>::from_event(__event) {
-                        ::core::result::Result::Ok(v) => v,
-                        ::core::result::Result::Err(e) => {
-                            return ::core::result::Result::Err(
-                                ::std::boxed::Box::new(e) as ::std::boxed::Box<dyn ::std::error::Error + Send + Sync>
-                            );
-                        }
-                    };
-                })
-            } else {
-                None
+    let (output_type, call_body) = if extracting {
+        let mut extraction_code = Vec::new();
+        let mut input_binding = None;
+
+        for (i, ((ty, extractor), arg_name)) in arg_types
+            .iter()
+            .zip(&extractors)
+            .zip(&arg_names)
+            .enumerate()
+        {
+            // An owned `In` takes the input once everything else is extracted
+            if extractor.is_none() && quote!(#ty).to_string() == input_type_str {
+                input_binding = Some(quote! { let #arg_name: #ty = __event; });
+                continue;
             }
-        })
-        .collect();
+            extraction_code.push(extraction_binding(
+                i,
+                ty,
+                extractor.as_ref(),
+                input_type,
+                arg_name,
+            ));
+        }
 
-    let first_arg = if let Some(FnArg::Typed(pat_type)) = inputs.first() {
-        let pat = &pat_type.pat;
-        quote! { let #pat = __event; }
+        (
+            quote! { ::core::result::Result<#ret_type, ::risten::ExtractError> },
+            quote! {
+                #(#extraction_code)*
+                #input_binding
+                #inner_fn
+                ::core::result::Result::Ok(__inner(#(#arg_names),*) #await_inner)
+            },
+        )
     } else {
-        quote! {}
+        let arg_type = arg_types[0];
+        let binding = if referenced_type(arg_type).is_some() {
+            quote! { &__event }
+        } else {
+            quote! { __event }
+        };
+
+        (
+            ret_type.clone(),
+            quote! {
+                #inner_fn
+                __inner(#binding) #await_inner
+            },
+        )
     };
 
     let priority_impl = args.priority.map(|p| {
@@ -158,26 +225,27 @@ pub fn handler_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
         }
     });
 
-    let expanded = quote! {
+    let (impl_generics, _, _) = generics.split_for_impl();
+
+    Ok(quote! {
         #[allow(non_camel_case_types)]
         #[derive(Clone, Copy, Debug, Default)]
         #[doc = concat!("Auto-generated Handler from `#[risten::handler]` on `", stringify!(#fn_name), "`")]
+        #(#doc_attrs)*
         #fn_vis struct #struct_name;
 
         #priority_impl
 
-        impl ::risten::Handler<#event_type> for #struct_name {
-            async fn handle(
-                &self,
-                __event: &#event_type,
-            ) -> ::core::result::Result<(), ::std::boxed::Box<dyn ::std::error::Error + Send + Sync>> {
-                #first_arg
-                #(#extractor_bindings)*
-                #fn_block
-                ::core::result::Result::Ok(())
+        impl #impl_generics ::risten::Handler<#input_type> for #struct_name #where_clause {
+            type Output = #output_type;
+
+            async fn call(&self, __event: #input_type) -> Self::Output {
+                #call_body
             }
-        }
-    };
 
-    TokenStream::from(expanded)
+            fn name(&self) -> ::std::borrow::Cow<'static, str> {
+                ::std::borrow::Cow::Borrowed(stringify!(#fn_name))
+            }
+        }
+    })
 }
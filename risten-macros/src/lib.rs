@@ -3,35 +3,97 @@
 //! This crate provides procedural macros for the Risten event framework:
 //!
 //! - `#[derive(Message)]` - Derive macro for implementing the `Message` trait
+//! - `#[derive(FromEvent)]` - Generate field projection extractors for an event
 //! - `#[event]` - Create Hook implementations from async functions
 //! - `#[handler]` - Create Handler implementations with extraction support
+//! - `#[listener]` - Create Listener implementations from functions
 //! - `#[subscribe]` / `#[on]` - Register handlers with the global dispatcher
-//! - `#[main]` - Wrap main function with tokio runtime
+//! - `#[subscriber]` - Subscribe the `#[on]` methods of a stateful service at runtime
+//! - `#[main]` - Wrap main function with a configured tokio runtime and lifecycle
 //! - `#[dispatch]` - Create dispatch implementations for enum types
+//! - `router!` - Declare static routes and pipelines for an event type
 
 mod event;
+mod extract;
 mod handler;
+mod listener;
 mod main_fn;
 mod router_macro;
 mod subscribe;
+mod subscriber;
 
 use proc_macro::TokenStream;
 
 /// Derive macro for implementing `Message` trait.
 ///
+/// On enums this also implements `Message::variant`, which lets
+/// `DispatchRouter` run only the subscribers of the event's variant
+/// (see `#[subscribe(Enum::Variant)]`).
+///
+/// It also implements `MessageMeta`, configured with `#[message(...)]`:
+///
+/// - `name` - Stable event name (defaults to the type's name)
+/// - `topic` - Optional topic string
+/// - `key` - Optional field used as the partition/routing key (structs only)
+///
 /// # Example
 ///
 /// ```rust,ignore
 /// #[derive(Message)]
+/// #[message(name = "chat.message", topic = "chat", key = channel_id)]
 /// struct MyEvent {
+///     channel_id: u64,
 ///     data: String,
 /// }
 /// ```
-#[proc_macro_derive(Message)]
+#[proc_macro_derive(Message, attributes(message))]
 pub fn derive_message(input: TokenStream) -> TokenStream {
     event::derive_message_impl(input)
 }
 
+/// Derive macro generating field projection extractors for an event struct.
+///
+/// Each field marked `#[extract(Name)]` gets a newtype `Name(pub FieldType)`
+/// implementing `FromEvent<Event>` (by cloning the field), usable as an
+/// argument of `ExtractHandler`, `#[handler]` and `#[subscribe]` functions.
+///
+/// Add `borrow` to also implement `FromEventGat<Event>` with
+/// `Output<'a> = &'a FieldType`, or `borrow = T` for `&'a T` via `AsRef<T>`,
+/// for zero-copy use with `BorrowedExtractHandler`, or in `#[subscribe]`
+/// functions as a borrowed argument marked `#[extract(Name)]`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[derive(Clone, Message, FromEvent)]
+/// struct MessageEvent {
+///     #[extract(Author)]
+///     author: String,
+///     #[extract(ChannelId)]
+///     channel_id: u64,
+///     #[extract(Content, borrow = str)]
+///     content: String,
+/// }
+///
+/// #[risten::subscribe(MessageEvent)]
+/// async fn on_message(author: Author, channel: ChannelId) { /* ... */ }
+///
+/// // Zero-copy: `content` borrows from the event
+/// #[risten::subscribe(MessageEvent)]
+/// async fn on_content(author: Author, #[extract(Content)] content: &str) { /* ... */ }
+///
+/// // Closures can't return futures borrowing their arguments; use an `async fn`
+/// async fn content_len(content: &str) -> usize {
+///     content.len()
+/// }
+///
+/// let handler = BorrowedExtractHandler::<_, MessageEvent, (Content,)>::new(content_len);
+/// ```
+#[proc_macro_derive(FromEvent, attributes(extract))]
+pub fn derive_from_event(input: TokenStream) -> TokenStream {
+    extract::derive_from_event_impl(input)
+}
+
 /// Attribute macro for creating Hook implementations from async functions.
 ///
 /// # Arguments
@@ -56,8 +118,24 @@ pub fn event(attr: TokenStream, item: TokenStream) -> TokenStream {
 
 /// Attribute macro for creating Handler implementations with extraction support.
 ///
+/// The generated unit struct implements `Handler<In>`, so it can be called
+/// directly or used in `Listener::handler(...)` pipelines, where the return
+/// value is converted with `IntoResponse`.
+///
+/// - A single argument `input: In` (or `input: &In`) receives the input, and
+///   `Output` is the function's return type.
+/// - With more arguments, `In` is the first argument's type and the others are
+///   extracted via `AsyncFromEvent<In>` (including every `FromEvent<In>`), or
+///   `FromEventGat<In>` for `&T`. `Output` is `Result<Ret, ExtractError>`.
+/// - `#[extract(X)] arg: &str` projects an argument through the extractor
+///   `X: FromEventGat<In>`, as with `#[subscribe]`.
+///
+/// Both `async` and plain functions are supported, as are generic parameters
+/// that appear in the input type.
+///
 /// # Arguments
 ///
+/// - A leading type - Optional explicit input type; all arguments are extracted from it
 /// - `name` - Optional custom struct name
 /// - `priority` - Optional priority value
 ///
@@ -65,9 +143,20 @@ pub fn event(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///
 /// ```rust,ignore
 /// #[risten::handler]
-/// async fn handle_message(event: &MessageEvent) -> Result<(), Box<dyn Error + Send + Sync>> {
-///     println!("Message: {:?}", event);
-///     Ok(())
+/// async fn echo(input: String) -> String {
+///     format!("Echo: {}", input)
+/// }
+///
+/// #[risten::handler]
+/// async fn greet(event: &MessageEvent, user: UserContext) -> HookResult {
+///     println!("{} said {}", user.name, event.content);
+///     HookResult::Stop
+/// }
+///
+/// // Extract everything from an explicit input type
+/// #[risten::handler(MessageEvent)]
+/// fn count(words: WordCount) -> usize {
+///     words.0
 /// }
 /// ```
 #[proc_macro_attribute]
@@ -75,6 +164,32 @@ pub fn handler(attr: TokenStream, item: TokenStream) -> TokenStream {
     handler::handler_impl(attr, item)
 }
 
+/// Attribute macro for creating Listener implementations from functions.
+///
+/// Turns `fn(event: &In) -> Option<Out>` (or `Result<Option<Out>, E>` where
+/// `E: Into<BoxError>`) into a unit struct implementing `Listener<In, Output = Out>`,
+/// which composes with `.filter()`, `.map()` and `.handler()`.
+/// Both `async` and plain functions are supported.
+///
+/// # Arguments
+///
+/// - `name` - Optional custom struct name
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[risten::listener]
+/// async fn commands(event: &MessageEvent) -> Option<String> {
+///     event.content.strip_prefix('!').map(str::to_string)
+/// }
+///
+/// let pipeline = commands.filter(|cmd| !cmd.is_empty()).handler(run_command);
+/// ```
+#[proc_macro_attribute]
+pub fn listener(attr: TokenStream, item: TokenStream) -> TokenStream {
+    listener::listener_impl(attr, item)
+}
+
 /// Subscribe a function to handle events of a specific type.
 ///
 /// This macro registers the function with the global handler registry,
@@ -106,6 +221,49 @@ pub fn handler(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// async fn with_context(event: MessageEvent, user: UserContext) {
 ///     // user is extracted via AsyncFromEvent
 /// }
+///
+/// // With borrowed arguments (`&T` is extracted via `T: FromEventGat<E>`)
+/// #[risten::subscribe]
+/// async fn zero_copy(event: &MessageEvent, content: &str) {
+///     // content borrows from the event
+/// }
+///
+/// // Naming the extractor, for projections whose output is not `&Self`
+/// // (`#[extract(X)]` extracts via `X: FromEventGat<E>`)
+/// #[risten::subscribe(MessageEvent)]
+/// async fn projected(#[extract(Content)] content: &str, #[extract(Author)] author: &str) {
+///     // both borrow from the event
+/// }
+///
+/// // Only the `Message` variant of an enum event, receiving its payload.
+/// // The enum must derive `Message`, so that `DispatchRouter<AppEvent>`
+/// // runs only the subscribers of the event's variant.
+/// #[risten::subscribe(AppEvent::Message)]
+/// async fn on_app_message(event: MessageEvent) {
+///     // ...
+/// }
+///
+/// // Filtered before extraction, named and tagged for introspection via
+/// // `HandlerRegistration`, and disabled after its first successful run
+/// #[risten::subscribe(filter = is_admin, name = "bootstrap", tags = ["admin"], once)]
+/// async fn bootstrap(event: MessageEvent) {
+///     // ...
+/// }
+///
+/// // Timeout per attempt, retries with exponential backoff on failure
+/// // (including `Err` returns), and a concurrency limit. `timeout` needs the
+/// // `timeout` feature; `retries` and `concurrency` need `resilience`.
+/// #[risten::subscribe(timeout = "5s", retries = 3, backoff = "200ms", concurrency = 1)]
+/// async fn sync_profile(event: MessageEvent) -> Result<(), ProfileError> {
+///     // ...
+/// }
+///
+/// // Runs in its own `risten.instrument` span, logging failures
+/// // (needs the `tracing` feature)
+/// #[risten::subscribe(instrument)]
+/// async fn audited(event: MessageEvent) -> Result<(), AuditError> {
+///     // ...
+/// }
 /// ```
 #[proc_macro_attribute]
 pub fn subscribe(attr: TokenStream, item: TokenStream) -> TokenStream {
@@ -115,7 +273,9 @@ pub fn subscribe(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// Alias for `#[subscribe]`.
 ///
 /// This macro is identical to `#[subscribe]` and can be used interchangeably.
-/// Some developers prefer `#[on]` for its brevity.
+/// Some developers prefer `#[on]` for its brevity. Inside a `#[subscriber]`
+/// impl block, `#[on]` marks the methods to subscribe instead, with fewer
+/// options; see [`macro@subscriber`].
 ///
 /// # Example
 ///
@@ -130,8 +290,70 @@ pub fn on(attr: TokenStream, item: TokenStream) -> TokenStream {
     subscribe::on_impl(attr, item)
 }
 
+/// Turns the `#[on]` methods of an impl block into runtime subscriptions.
+///
+/// The type implements `Subscriber`: calling `subscribe` on an `Arc` of an
+/// instance registers a handler for every `#[on]` method with
+/// `DispatchRouter`, sharing that instance and its state. The returned
+/// `Subscription` unregisters all of them at once.
+///
+/// `#[on]` accepts the options of `#[subscribe]` except `name`, `tags` and
+/// variant subscriptions (`#[on(Enum::Variant)]`), which are rejected at
+/// compile time. Handlers are named `Type::method`, and runtime handlers are
+/// not listed by `DispatchRouter::registrations`, so they carry no tags. To
+/// handle a single variant, subscribe to the enum and match on it.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// struct Greeter {
+///     greeted: AtomicUsize,
+/// }
+///
+/// #[risten::subscriber]
+/// impl Greeter {
+///     #[on]
+///     async fn on_join(&self, event: JoinEvent) {
+///         self.greeted.fetch_add(1, Ordering::Relaxed);
+///     }
+///
+///     #[on(priority = 10, filter = is_command)]
+///     async fn on_message(&self, event: &MessageEvent, user: UserContext) -> Result<(), Error> {
+///         // ...
+///     }
+/// }
+///
+/// let subscription = Arc::new(Greeter::default()).subscribe();
+/// // ...
+/// subscription.unregister();
+/// ```
+#[proc_macro_attribute]
+pub fn subscriber(attr: TokenStream, item: TokenStream) -> TokenStream {
+    subscriber::subscriber_impl(attr, item)
+}
+
 /// Wraps the main function with `#[tokio::main]` for async runtime support.
 ///
+/// # Options
+///
+/// - `flavor = "multi_thread"` / `"current_thread"` and `workers = N`
+///   configure the tokio runtime.
+/// - `router = Type` constructs the router with `Default` and checks it with
+///   `ValidateRouter` after the startup hook, so handlers the hook registers
+///   at runtime count. If the function takes one argument, the router is
+///   passed to it and the argument's type is used.
+/// - `tracing` installs `tracing_subscriber::fmt` (needs the `tracing`
+///   feature); `tracing = init` calls a custom initializer instead.
+/// - `startup = hook` and `shutdown = hook` name async fns returning
+///   `Result<(), E>`, run before and after the body. A failed shutdown hook
+///   is logged with `tracing::error!` under the `tracing` feature, and
+///   printed to stderr otherwise.
+/// - `graceful_shutdown` (or `graceful_shutdown = "30s"`) stops the body on
+///   Ctrl-C or `SIGTERM` after the grace period (10s by default), letting it
+///   observe `risten::runtime::shutdown_signal()` to finish early.
+///
+/// Every option except the runtime ones needs the `runtime` feature.
+///
 /// # Example
 ///
 /// ```rust,ignore
@@ -139,6 +361,17 @@ pub fn on(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// async fn main() {
 ///     // Your async code here
 /// }
+///
+/// #[risten::main(
+///     flavor = "multi_thread",
+///     workers = 4,
+///     startup = connect,
+///     shutdown = flush,
+///     graceful_shutdown = "30s",
+/// )]
+/// async fn main(router: DispatchRouter<AppEvent>) {
+///     // ...
+/// }
 /// ```
 #[proc_macro_attribute]
 pub fn main(attr: TokenStream, item: TokenStream) -> TokenStream {
@@ -147,24 +380,76 @@ pub fn main(attr: TokenStream, item: TokenStream) -> TokenStream {
 
 /// Creates dispatch implementations for enum types.
 ///
-/// Generates the following methods:
+/// Generates the following:
 /// - `dispatch_match()` - Returns `HookResult` based on variant
 /// - `variant_name()` - Returns the variant name as a string
-/// - `dispatch_to_hooks()` - Async dispatch to registered hooks
+/// - `dispatch_to_hooks()` - Async dispatch to the routed hooks
+/// - `{Enum}Router` - A type implementing `Router<Enum>` that holds the hook instances
+///
+/// # Arguments
+///
+/// - `router` - Optional name for the generated router type
+///
+/// # Routing
 ///
-/// # Handler Registration
+/// Use `#[route(HookA, HookB)]` on a variant to run its hooks as a static
+/// chain, in order, until one returns `Stop`. Hooks are built with `Default`.
+/// Single-field tuple variants pass their payload to the hooks; struct, unit
+/// and multi-field variants pass the whole enum. Unrouted variants return `Next`.
+/// The router holds each chain in a field named after its variant in
+/// snake case, as a raw identifier for keywords (`Move` => `r#move`).
 ///
-/// Use `@handler(HandlerPath)` in doc comments to register handlers:
+/// `#[dispatch]` does not implement `Message`. To also subscribe to the
+/// enum's variants with `#[subscribe(Enum::Variant)]`, add
+/// `#[derive(Message)]`, which reports the variant to `DispatchRouter`;
+/// subscribing to variants of an enum without it fails to compile.
 ///
 /// ```rust,ignore
 /// #[risten::dispatch]
 /// enum MyEvent {
-///     /// @handler(my_handler)
+///     #[route(log_message, on_message)]
 ///     Message(MessageData),
+///     #[route(on_join)]
+///     Join { user: String },
 ///     Disconnect,
 /// }
+///
+/// let router = MyEventRouter::new();
+/// router.route(&event).await?;
 /// ```
+///
+/// The older `/// @handler(path)` doc-comment form is still accepted.
 #[proc_macro_attribute]
 pub fn dispatch(attr: TokenStream, item: TokenStream) -> TokenStream {
     router_macro::dispatch_impl(attr, item)
 }
+
+/// Declares a static router for an event type.
+///
+/// Expands into a `StaticRouter` over a `static_hooks!` chain, so the whole
+/// router is a concrete, zero-cost type. Routes run in order until one
+/// returns `Stop`:
+///
+/// - a single expression is a `Hook`;
+/// - `stage => ... => handler` is a `Pipeline`. `filter(f)`, `map(f)`,
+///   `then(f)` and `filter_map(f)` apply the `Listener` combinator of that
+///   name; any other stage is chained as a `Listener`. A pipeline starting
+///   with a combinator receives the event itself;
+/// - `fallback => hook` must come last and runs when no route stopped.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let router = risten::router! {
+///     MessageEvent => [
+///         LoggingHook,
+///         filter(is_command) => map(parse) => handle_command,
+///         fallback => ignore,
+///     ]
+/// };
+/// router.route(&event).await?;
+/// ```
+#[proc_macro]
+pub fn router(input: TokenStream) -> TokenStream {
+    router_macro::router_impl(input)
+}
@@ -0,0 +1,180 @@
+//! Listener-related macros.
+//!
+//! This module contains:
+//! - `#[listener]` - Attribute macro for creating Listener implementations from functions
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{
+    FnArg, GenericArgument, Ident, ItemFn, LitStr, PathArguments, ReturnType, Token, Type,
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+};
+
+/// Arguments for the `#[listener]` macro.
+pub(crate) struct ListenerArgs {
+    pub name: Option<String>,
+}
+
+impl Parse for ListenerArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut name = None;
+
+        while !input.is_empty() {
+            let ident: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+
+            match ident.to_string().as_str() {
+                "name" => {
+                    let lit: LitStr = input.parse()?;
+                    name = Some(lit.value());
+                }
+                other => {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        format!("unknown attribute: {}", other),
+                    ));
+                }
+            }
+
+            if input.peek(Token![,]) {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(ListenerArgs { name })
+    }
+}
+
+/// Returns the generic arguments of `ty` if its last path segment is `wrapper`.
+fn wrapped_types<'a>(ty: &'a Type, wrapper: &str) -> Option<Vec<&'a Type>> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != wrapper {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    Some(
+        args.args
+            .iter()
+            .filter_map(|arg| match arg {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .collect(),
+    )
+}
+
+/// Implementation of the `#[listener]` attribute macro.
+pub fn listener_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(attr as ListenerArgs);
+    let input = parse_macro_input!(item as ItemFn);
+
+    match expand_listener(args, &input) {
+        Ok(expanded) => TokenStream::from(expanded),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand_listener(args: ListenerArgs, input: &ItemFn) -> syn::Result<proc_macro2::TokenStream> {
+    let fn_name = &input.sig.ident;
+    let fn_vis = &input.vis;
+    let fn_block = &input.block;
+    let (doc_attrs, fn_attrs): (Vec<_>, Vec<_>) = input
+        .attrs
+        .iter()
+        .partition(|attr| attr.path().is_ident("doc"));
+    let is_async = input.sig.asyncness.is_some();
+
+    let inputs = &input.sig.inputs;
+    let (event_pat, event_ref, event_type) = match (inputs.first(), inputs.len()) {
+        (Some(FnArg::Typed(pat_type)), 1) => match &*pat_type.ty {
+            Type::Reference(type_ref) => (&pat_type.pat, &pat_type.ty, &type_ref.elem),
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &pat_type.ty,
+                    "Listener event argument must be a reference (&Event)",
+                ));
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                inputs,
+                "Listener function must take exactly one event argument: fn(event: &Event)",
+            ));
+        }
+    };
+
+    // `Option<Out>` or `Result<Option<Out>, E>`
+    let return_error = || {
+        syn::Error::new_spanned(
+            &input.sig.output,
+            "Listener function must return `Option<Out>` or `Result<Option<Out>, E>`",
+        )
+    };
+    let ReturnType::Type(_, ret_type) = &input.sig.output else {
+        return Err(return_error());
+    };
+    let (output_type, fallible) =
+        if let Some([option]) = wrapped_types(ret_type, "Option").as_deref() {
+            (*option, false)
+        } else if let Some([ok, _]) = wrapped_types(ret_type, "Result").as_deref() {
+            match wrapped_types(ok, "Option").as_deref() {
+                Some([option]) => (*option, true),
+                _ => return Err(return_error()),
+            }
+        } else {
+            return Err(return_error());
+        };
+
+    let struct_name = if let Some(ref custom_name) = args.name {
+        Ident::new(custom_name, fn_name.span())
+    } else {
+        fn_name.clone()
+    };
+
+    // The user function is kept intact so that `return` and `?` behave as written
+    let generics = &input.sig.generics;
+    let where_clause = &generics.where_clause;
+    let asyncness = &input.sig.asyncness;
+    let await_inner = is_async.then(|| quote! { .await });
+    let result = if fallible {
+        quote! { __result.map_err(::core::convert::Into::into) }
+    } else {
+        quote! { ::core::result::Result::Ok(__result) }
+    };
+
+    let (impl_generics, _, _) = generics.split_for_impl();
+
+    Ok(quote! {
+        #[allow(non_camel_case_types)]
+        #[derive(Clone, Copy, Debug, Default)]
+        #[doc = concat!("Auto-generated Listener from `#[risten::listener]` on `", stringify!(#fn_name), "`")]
+        #(#doc_attrs)*
+        #fn_vis struct #struct_name;
+
+        impl #impl_generics ::risten::Listener<#event_type> for #struct_name #where_clause {
+            type Output = #output_type;
+
+            async fn listen(
+                &self,
+                __event: &#event_type,
+            ) -> ::core::result::Result<::core::option::Option<Self::Output>, ::risten::BoxError> {
+                #(#fn_attrs)*
+                #asyncness fn __inner #generics (#event_pat: #event_ref) -> #ret_type #where_clause
+                    #fn_block
+
+                let __result = __inner(__event) #await_inner;
+                #result
+            }
+
+            fn name(&self) -> ::std::borrow::Cow<'static, str> {
+                ::std::borrow::Cow::Borrowed(stringify!(#fn_name))
+            }
+        }
+    })
+}
@@ -5,11 +5,93 @@
 
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{ItemFn, parse_macro_input};
+use syn::{
+    FnArg, Ident, ItemFn, LitInt, LitStr, Path, Token, Type,
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+};
+
+/// Grace period for `graceful_shutdown` without an explicit duration.
+const DEFAULT_GRACE_MILLIS: u64 = 10_000;
+
+/// Arguments for the `#[main]` macro.
+#[derive(Default)]
+pub(crate) struct MainArgs {
+    /// Runtime flavor: `"current_thread"` or `"multi_thread"`.
+    pub flavor: Option<LitStr>,
+    /// Number of worker threads of the multi-threaded runtime.
+    pub workers: Option<LitInt>,
+    /// Router type to construct and validate after startup.
+    pub router: Option<Type>,
+    /// Install tracing: `None` for the `tracing_subscriber` default (re-exported by
+    /// `risten` behind its `tracing` feature), or a custom initializer.
+    pub tracing: Option<Option<Path>>,
+    /// Async fn run before the main body.
+    pub startup: Option<Path>,
+    /// Async fn run after the main body, including after a graceful shutdown.
+    pub shutdown: Option<Path>,
+    /// Grace period in milliseconds once Ctrl-C or `SIGTERM` is received.
+    pub graceful_shutdown: Option<u64>,
+}
+
+impl Parse for MainArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut args = MainArgs::default();
+
+        while !input.is_empty() {
+            let ident: Ident = input.parse()?;
+            let has_value = input.peek(Token![=]);
+            if has_value {
+                input.parse::<Token![=]>()?;
+            }
+
+            match (ident.to_string().as_str(), has_value) {
+                ("flavor", true) => {
+                    let lit: LitStr = input.parse()?;
+                    if !matches!(lit.value().as_str(), "current_thread" | "multi_thread") {
+                        return Err(syn::Error::new(
+                            lit.span(),
+                            "flavor must be \"current_thread\" or \"multi_thread\"",
+                        ));
+                    }
+                    args.flavor = Some(lit);
+                }
+                ("workers", true) => args.workers = Some(input.parse()?),
+                ("router", true) => args.router = Some(input.parse()?),
+                ("tracing", true) => args.tracing = Some(Some(input.parse()?)),
+                ("tracing", false) => args.tracing = Some(None),
+                ("startup", true) => args.startup = Some(input.parse()?),
+                ("shutdown", true) => args.shutdown = Some(input.parse()?),
+                ("graceful_shutdown", true) => {
+                    args.graceful_shutdown =
+                        Some(crate::subscribe::parse_duration_millis(&input.parse()?)?);
+                }
+                ("graceful_shutdown", false) => {
+                    args.graceful_shutdown = Some(DEFAULT_GRACE_MILLIS);
+                }
+                (other, _) => {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        format!("unknown attribute: {}", other),
+                    ));
+                }
+            }
+
+            if input.peek(Token![,]) {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(args)
+    }
+}
 
 /// Implementation of the `#[main]` macro.
 ///
 /// This macro wraps the function with `#[tokio::main]` to enable async runtime.
+/// Options configure the runtime, construct and validate a router (passed to
+/// the function if it takes one argument), install tracing, run startup and
+/// shutdown hooks and shut down gracefully on Ctrl-C or `SIGTERM`.
 ///
 /// # Example
 ///
@@ -19,11 +101,140 @@ use syn::{ItemFn, parse_macro_input};
 ///     // Your async code here
 /// }
 /// ```
-pub fn main_impl(_attr: TokenStream, item: TokenStream) -> TokenStream {
+pub fn main_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(attr as MainArgs);
     let input = parse_macro_input!(item as ItemFn);
-    let expanded = quote! {
-        #[::tokio::main]
-        #input
+
+    match expand_main(args, input) {
+        Ok(expanded) => TokenStream::from(expanded),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand_main(args: MainArgs, mut input: ItemFn) -> syn::Result<proc_macro2::TokenStream> {
+    if input.sig.asyncness.is_none() {
+        return Err(syn::Error::new_spanned(
+            input.sig.fn_token,
+            "#[risten::main] function must be async",
+        ));
+    }
+
+    let mut tokio_args = Vec::new();
+    if let Some(flavor) = &args.flavor {
+        tokio_args.push(quote! { flavor = #flavor });
+    }
+    if let Some(workers) = &args.workers {
+        tokio_args.push(quote! { worker_threads = #workers });
+    }
+
+    // The router is handed to the body if it takes an argument
+    let router_param = match input.sig.inputs.len() {
+        0 => None,
+        1 => match &input.sig.inputs[0] {
+            FnArg::Typed(pat_type) => Some(pat_type.clone()),
+            FnArg::Receiver(receiver) => {
+                return Err(syn::Error::new_spanned(
+                    receiver,
+                    "#[risten::main] function cannot take `self`",
+                ));
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.sig.inputs,
+                "#[risten::main] function takes at most one argument: the router",
+            ));
+        }
+    };
+    let router_type = match (&router_param, &args.router) {
+        (Some(param), _) => Some((*param.ty).clone()),
+        (None, router) => router.clone(),
+    };
+
+    // Nothing to set up: keep the plain `#[tokio::main]` expansion
+    if router_type.is_none()
+        && args.tracing.is_none()
+        && args.startup.is_none()
+        && args.shutdown.is_none()
+        && args.graceful_shutdown.is_none()
+    {
+        return Ok(quote! {
+            #[::tokio::main(#(#tokio_args),*)]
+            #input
+        });
+    }
+
+    let tracing_init = args.tracing.as_ref().map(|init| match init {
+        Some(init) => quote! { #init(); },
+        None => quote! { ::risten::__private::tracing_subscriber::fmt::init(); },
+    });
+
+    let router_init = router_type.as_ref().map(|router_type| {
+        quote! {
+            let __router: #router_type = ::core::default::Default::default();
+            if let ::core::result::Result::Err(err) = ::risten::routing::ValidateRouter::validate(&__router) {
+                panic!("invalid router `{}`: {}", stringify!(#router_type), err);
+            }
+        }
+    });
+
+    let startup = args.startup.as_ref().map(|startup| {
+        quote! {
+            if let ::core::result::Result::Err(err) = #startup().await {
+                panic!("startup hook `{}` failed: {}", stringify!(#startup), err);
+            }
+        }
+    });
+    let shutdown = args.shutdown.as_ref().map(|shutdown| {
+        quote! {
+            if let ::core::result::Result::Err(err) = #shutdown().await {
+                ::risten::__private::shutdown_hook_failed(stringify!(#shutdown), &err);
+            }
+        }
+    });
+
+    // The user function is kept intact so that `return` and `?` behave as written
+    let output = &input.sig.output;
+    let block = &input.block;
+    let inner_params = router_param.iter();
+    let inner_args = router_param.iter().map(|_| quote! { __router });
+    let inner = quote! {
+        async fn __main(#(#inner_params),*) #output #block
     };
-    TokenStream::from(expanded)
+    let run = match args.graceful_shutdown {
+        Some(millis) => quote! {
+            let __output = ::risten::runtime::run_until_shutdown(
+                __main(#(#inner_args),*),
+                ::core::time::Duration::from_millis(#millis),
+            )
+            .await;
+            #shutdown
+            match __output {
+                ::core::option::Option::Some(output) => output,
+                ::core::option::Option::None => ::risten::runtime::ShutdownOutput::shutdown(),
+            }
+        },
+        None => quote! {
+            let __output = __main(#(#inner_args),*).await;
+            #shutdown
+            __output
+        },
+    };
+
+    input.sig.inputs.clear();
+    let attrs = &input.attrs;
+    let vis = &input.vis;
+    let sig = &input.sig;
+
+    Ok(quote! {
+        #(#attrs)*
+        #[::tokio::main(#(#tokio_args),*)]
+        #vis #sig {
+            #tracing_init
+            #startup
+            #router_init
+            #inner
+            #run
+        }
+    })
 }
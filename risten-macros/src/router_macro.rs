@@ -2,30 +2,71 @@
 //!
 //! This module contains:
 //! - `#[dispatch]` - Attribute macro for creating dispatch implementations from enums
+//! - `router!` - Function-like macro declaring static routes and pipelines
 
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{Attribute, Data, DeriveInput, Meta, parse_macro_input};
+use quote::{format_ident, quote, quote_spanned};
+use syn::{
+    Attribute, Data, DeriveInput, Expr, Fields, Ident, Meta, Path, Token, Type,
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    punctuated::Punctuated,
+    spanned::Spanned,
+};
+
+/// Arguments for the `#[dispatch]` macro.
+pub(crate) struct DispatchArgs {
+    /// Optional name of the generated router type.
+    pub router: Option<Ident>,
+}
+
+impl Parse for DispatchArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut router = None;
+
+        while !input.is_empty() {
+            let ident: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+
+            match ident.to_string().as_str() {
+                "router" => {
+                    router = Some(input.parse()?);
+                }
+                other => {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        format!("unknown attribute: {}", other),
+                    ));
+                }
+            }
+
+            if input.peek(Token![,]) {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(DispatchArgs { router })
+    }
+}
 
 /// Extracts handler path from doc comments.
 ///
 /// Looks for `@handler(HandlerPath)` in doc comments and parses the handler path.
-pub(crate) fn extract_handler_attr(attrs: &[Attribute]) -> Option<syn::Path> {
+/// Kept for compatibility; prefer `#[route(...)]`.
+pub(crate) fn extract_handler_attr(attrs: &[Attribute]) -> Option<Path> {
     for attr in attrs {
-        if attr.path().is_ident("doc") {
-            if let Meta::NameValue(nv) = &attr.meta {
-                if let syn::Expr::Lit(expr_lit) = &nv.value {
-                    if let syn::Lit::Str(lit_str) = &expr_lit.lit {
-                        let content = lit_str.value();
-                        if let Some(start) = content.find("@handler(") {
-                            let after = &content[start + 9..];
-                            if let Some(end) = after.find(')') {
-                                let handler_name = after[..end].trim();
-                                if let Ok(path) = syn::parse_str::<syn::Path>(handler_name) {
-                                    return Some(path);
-                                }
-                            }
-                        }
+        if attr.path().is_ident("doc")
+            && let Meta::NameValue(nv) = &attr.meta
+            && let syn::Expr::Lit(expr_lit) = &nv.value
+            && let syn::Lit::Str(lit_str) = &expr_lit.lit
+        {
+            let content = lit_str.value();
+            if let Some(start) = content.find("@handler(") {
+                let after = &content[start + 9..];
+                if let Some(end) = after.find(')') {
+                    let handler_name = after[..end].trim();
+                    if let Ok(path) = syn::parse_str::<Path>(handler_name) {
+                        return Some(path);
                     }
                 }
             }
@@ -34,124 +75,161 @@ pub(crate) fn extract_handler_attr(attrs: &[Attribute]) -> Option<syn::Path> {
     None
 }
 
+/// Collects the hook types of a variant from `#[route(...)]` attributes.
+fn extract_route_attrs(attrs: &[Attribute]) -> syn::Result<Vec<Type>> {
+    let mut hooks = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("route")) {
+        let types = attr.parse_args_with(Punctuated::<Type, Token![,]>::parse_terminated)?;
+        hooks.extend(types);
+    }
+    Ok(hooks)
+}
+
+/// Converts a `CamelCase` variant name into a `snake_case` field name.
+fn to_snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
+
+/// The router field holding the hooks of `variant`: its snake-cased name,
+/// as a raw identifier if it is a keyword (`Move` => `r#move`). The keywords
+/// raw identifiers cannot spell get a trailing `_` (`Crate` => `crate_`).
+fn variant_field(variant: &Ident) -> Ident {
+    let name = to_snake_case(&variant.to_string());
+    match name.as_str() {
+        "crate" | "self" | "super" => format_ident!("{}_", name, span = variant.span()),
+        _ if syn::parse_str::<Ident>(&name).is_err() => Ident::new_raw(&name, variant.span()),
+        _ => Ident::new(&name, variant.span()),
+    }
+}
+
 /// Implementation of the `#[dispatch]` macro.
 ///
 /// Creates dispatch implementations for enum types, generating:
 /// - `dispatch_match()` - Returns `HookResult` based on variant
 /// - `variant_name()` - Returns the variant name as a string
-/// - `dispatch_to_hooks()` - Async dispatch to registered hooks
+/// - `dispatch_to_hooks()` - Async dispatch to the routed hooks
+/// - `{Enum}Router` - A `Router<Enum>` holding the routed hook instances
 ///
 /// # Example
 ///
 /// ```rust,ignore
 /// #[risten::dispatch]
 /// enum MyEvent {
-///     /// @handler(my_handler)
+///     #[route(log_message, on_message)]
 ///     Message(MessageData),
+///     #[route(on_join)]
+///     Join { user: String },
 ///     Disconnect,
 /// }
 /// ```
-pub fn dispatch_impl(_attr: TokenStream, item: TokenStream) -> TokenStream {
+pub fn dispatch_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(attr as DispatchArgs);
     let input = parse_macro_input!(item as DeriveInput);
-    let enum_name = &input.ident;
-    let _vis = &input.vis;
 
-    let variants = match &input.data {
-        Data::Enum(data_enum) => &data_enum.variants,
-        _ => {
-            return syn::Error::new_spanned(&input, "#[dispatch] can only be used on enums")
-                .to_compile_error()
-                .into();
-        }
+    match expand_dispatch(args, input) {
+        Ok(expanded) => TokenStream::from(expanded),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand_dispatch(
+    args: DispatchArgs,
+    mut input: DeriveInput,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let enum_name = input.ident.clone();
+    let vis = input.vis.clone();
+    let router_name = args
+        .router
+        .unwrap_or_else(|| format_ident!("{}Router", enum_name));
+
+    let Data::Enum(data_enum) = &mut input.data else {
+        return Err(syn::Error::new_spanned(
+            &input,
+            "#[dispatch] can only be used on enums",
+        ));
     };
 
-    let match_arms = variants.iter().map(|variant| {
+    let mut match_arms = Vec::new();
+    let mut name_arms = Vec::new();
+    let mut route_arms = Vec::new();
+    let mut router_fields = Vec::new();
+    let mut router_defaults = Vec::new();
+
+    for variant in &mut data_enum.variants {
         let variant_name = &variant.ident;
-        match &variant.fields {
-            syn::Fields::Unnamed(_) => {
-                quote! {
-                    #enum_name::#variant_name(_inner) => {
-                        ::risten::HookResult::Next
-                    }
-                }
-            }
-            syn::Fields::Unit => {
-                quote! {
-                    #enum_name::#variant_name => {
-                        ::risten::HookResult::Next
-                    }
-                }
-            }
-            _ => {
-                quote! {
-                    #enum_name::#variant_name { .. } => {
-                        ::risten::HookResult::Next
-                    }
-                }
-            }
+        let pattern = match &variant.fields {
+            Fields::Unnamed(_) => quote! { #enum_name::#variant_name(..) },
+            Fields::Named(_) => quote! { #enum_name::#variant_name { .. } },
+            Fields::Unit => quote! { #enum_name::#variant_name },
+        };
+
+        match_arms.push(quote! { #pattern => ::risten::HookResult::Next });
+        name_arms.push(quote! { #pattern => stringify!(#variant_name) });
+
+        let mut hooks = extract_route_attrs(&variant.attrs)?;
+        if hooks.is_empty()
+            && let Some(handler) = extract_handler_attr(&variant.attrs)
+        {
+            hooks.push(syn::parse_quote!(#handler));
         }
-    });
+        // `#[route]` is only meaningful to this macro
+        variant.attrs.retain(|attr| !attr.path().is_ident("route"));
 
-    let name_arms = variants.iter().map(|variant| {
-        let variant_name = &variant.ident;
-        match &variant.fields {
-            syn::Fields::Unnamed(_) => {
-                quote! {
-                    #enum_name::#variant_name(_) => stringify!(#variant_name)
-                }
-            }
-            syn::Fields::Unit => {
-                quote! {
-                    #enum_name::#variant_name => stringify!(#variant_name)
-                }
-            }
-            _ => {
-                quote! {
-                    #enum_name::#variant_name { .. } => stringify!(#variant_name)
-                }
-            }
+        if hooks.is_empty() {
+            route_arms.push(quote! {
+                #pattern => ::core::result::Result::Ok(::risten::HookResult::Next)
+            });
+            continue;
         }
-    });
 
-    let static_dispatch_arms = variants.iter().map(|variant| {
-        let variant_name = &variant.ident;
-        let handler_path = extract_handler_attr(&variant.attrs);
-
-        match &variant.fields {
-            syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
-                if let Some(handler) = handler_path {
-                    quote! {
-                        #enum_name::#variant_name(inner) => {
-                            let hook = #handler;
-                            ::risten::Hook::on_event(&hook, inner).await
-                        }
-                    }
-                } else {
-                    quote! {
-                        #enum_name::#variant_name(_) => {
-                            ::core::result::Result::Ok(::risten::HookResult::Next)
-                        }
-                    }
-                }
-            }
-            syn::Fields::Unit => {
-                quote! {
-                    #enum_name::#variant_name => {
-                        ::core::result::Result::Ok(::risten::HookResult::Next)
-                    }
-                }
-            }
-            _ => {
+        // Single-field tuple variants route their payload; others route the whole enum
+        let (arm_pattern, routed) = match &variant.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => (
+                quote! { #enum_name::#variant_name(__inner) },
+                quote! { __inner },
+            ),
+            _ => (pattern, quote! { event }),
+        };
+
+        let field = variant_field(variant_name);
+        let chain_type = hooks.iter().rev().fold(
+            quote! { ::risten::HNil },
+            |tail, hook| quote! { ::risten::HCons<#hook, #tail> },
+        );
+        let chain_default = hooks
+            .iter()
+            .rev()
+            .fold(quote! { ::risten::HNil }, |tail, hook| {
                 quote! {
-                    #enum_name::#variant_name { .. } => {
-                        ::core::result::Result::Ok(::risten::HookResult::Next)
+                    ::risten::HCons {
+                        head: <#hook as ::core::default::Default>::default(),
+                        tail: #tail,
                     }
                 }
-            }
-        }
-    });
+            });
 
-    let expanded = quote! {
+        router_fields.push(quote! {
+            #[doc = concat!("Hooks routed for `", stringify!(#enum_name), "::", stringify!(#variant_name), "`.")]
+            #vis #field: #chain_type
+        });
+        router_defaults.push(quote! { #field: #chain_default });
+        route_arms.push(quote! {
+            #arm_pattern => ::risten::HookChain::dispatch_chain(&self.#field, #routed).await
+        });
+    }
+
+    Ok(quote! {
         #input
 
         impl #enum_name {
@@ -169,14 +247,221 @@ pub fn dispatch_impl(_attr: TokenStream, item: TokenStream) -> TokenStream {
                 }
             }
 
-            /// Asynchronously dispatches to the registered hooks.
-            pub async fn dispatch_to_hooks(&self) -> ::core::result::Result<::risten::HookResult, ::std::boxed::Box<dyn ::std::error::Error + Send + Sync>> {
-                match self {
-                    #(#static_dispatch_arms),*
+            /// Asynchronously dispatches to the routed hooks.
+            ///
+            #[doc = concat!("Uses default hook instances; keep a [`", stringify!(#router_name), "`] to reuse configured ones.")]
+            pub async fn dispatch_to_hooks(&self) -> ::core::result::Result<::risten::HookResult, ::risten::RoutingError> {
+                <#router_name as ::core::default::Default>::default()
+                    .dispatch(self)
+                    .await
+            }
+        }
+
+        #[doc = concat!("Router for [`", stringify!(#enum_name), "`] generated by `#[risten::dispatch]`.")]
+        ///
+        /// Each routed variant holds a static chain of its hooks, run in order
+        /// until one returns `Stop`.
+        #vis struct #router_name {
+            #(#router_fields,)*
+        }
+
+        impl ::core::default::Default for #router_name {
+            fn default() -> Self {
+                Self {
+                    #(#router_defaults,)*
                 }
             }
         }
-    };
 
-    TokenStream::from(expanded)
+        impl #router_name {
+            /// Creates a router with default hook instances.
+            pub fn new() -> Self {
+                ::core::default::Default::default()
+            }
+
+            /// Runs the hooks routed for the event's variant.
+            pub async fn dispatch(&self, event: &#enum_name) -> ::core::result::Result<::risten::HookResult, ::risten::RoutingError> {
+                match event {
+                    #(#route_arms),*
+                }
+            }
+        }
+
+        // Higher-ranked so that enums which are not a `Message` still compile
+        impl ::risten::Router<#enum_name> for #router_name
+        where
+            for<'__r> #enum_name: ::risten::Message,
+        {
+            type Error = ::risten::RoutingError;
+
+            async fn route(&self, event: &#enum_name) -> ::core::result::Result<::risten::RouteResult, Self::Error> {
+                let result = self.dispatch(event).await?;
+
+                ::core::result::Result::Ok(::risten::RouteResult {
+                    stopped: result == ::risten::HookResult::Stop,
+                    executed_count: 0, // Static chains don't track count
+                })
+            }
+        }
+    })
+}
+
+/// Input of the `router!` macro: `Event => [route, ...]`.
+pub(crate) struct RouterInput {
+    event_type: Type,
+    routes: Punctuated<Route, Token![,]>,
+}
+
+/// One route of `router!`: `a => b => ... => handler`, or a single hook.
+struct Route {
+    stages: Vec<Expr>,
+}
+
+impl Parse for RouterInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let event_type = input.parse()?;
+        input.parse::<Token![=>]>()?;
+        let content;
+        syn::bracketed!(content in input);
+        let routes = Punctuated::parse_terminated(&content)?;
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+        if !input.is_empty() {
+            return Err(input.error("router! takes a single `Event => [...]` block"));
+        }
+        Ok(RouterInput { event_type, routes })
+    }
+}
+
+impl Parse for Route {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut stages = vec![input.parse()?];
+        while input.peek(Token![=>]) {
+            input.parse::<Token![=>]>()?;
+            stages.push(input.parse()?);
+        }
+        Ok(Route { stages })
+    }
+}
+
+/// A pipeline stage: a listener combinator or a listener.
+enum Stage<'a> {
+    /// `filter(..)`, `map(..)`, `then(..)` or `filter_map(..)`.
+    Combinator(&'a Ident, &'a Expr),
+    /// Any other expression, used as a `Listener`.
+    Listener(&'a Expr),
+}
+
+const COMBINATORS: &[&str] = &["filter", "map", "then", "filter_map"];
+
+fn stage(expr: &Expr) -> Stage<'_> {
+    if let Expr::Call(call) = expr
+        && call.args.len() == 1
+        && let Expr::Path(func) = &*call.func
+        && let Some(ident) = func.path.get_ident()
+        && COMBINATORS.contains(&ident.to_string().as_str())
+    {
+        return Stage::Combinator(ident, &call.args[0]);
+    }
+    Stage::Listener(expr)
+}
+
+/// Returns `true` if `expr` is the bare keyword `fallback`.
+fn is_fallback(expr: &Expr) -> bool {
+    matches!(expr, Expr::Path(path) if path.path.is_ident("fallback"))
+}
+
+/// Implementation of the `router!` macro.
+///
+/// Each route becomes one hook of a `static_hooks!` chain wrapped in a
+/// `StaticRouter`:
+///
+/// - a single expression is used as a `Hook`;
+/// - `a => b => ... => handler` becomes a `Pipeline`: `filter(..)`, `map(..)`,
+///   `then(..)` and `filter_map(..)` apply the listener combinator, any other
+///   stage is chained as a `Listener`, and the last stage is the `Handler`;
+/// - `fallback => hook` must come last and runs when no route returned `Stop`.
+pub fn router_impl(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as RouterInput);
+
+    match expand_router(&input) {
+        Ok(expanded) => TokenStream::from(expanded),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand_router(input: &RouterInput) -> syn::Result<proc_macro2::TokenStream> {
+    let event_type = &input.event_type;
+    let route_count = input.routes.len();
+    let mut hooks = Vec::new();
+
+    for (index, route) in input.routes.iter().enumerate() {
+        let stages = &route.stages;
+        let span = stages[0].span();
+
+        // `fallback => hook`
+        if is_fallback(&stages[0]) {
+            let [_, hook] = stages.as_slice() else {
+                return Err(syn::Error::new(span, "expected `fallback => hook`"));
+            };
+            if index + 1 != route_count {
+                return Err(syn::Error::new(span, "`fallback` must be the last route"));
+            }
+            hooks.push(quote_spanned! {hook.span()=> __route(#hook) });
+            continue;
+        }
+
+        let (handler, listeners) = stages.split_last().unwrap();
+        if listeners.is_empty() {
+            if let Stage::Combinator(ident, _) = stage(handler) {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    format!("`{}(..)` must be followed by `=> handler`", ident),
+                ));
+            }
+            hooks.push(quote_spanned! {span=> __route(#handler) });
+            continue;
+        }
+
+        // A leading listener starts the pipeline; otherwise start from the event itself
+        let (mut pipeline, rest) = match stage(&listeners[0]) {
+            Stage::Listener(listener) => (quote! { (#listener) }, &listeners[1..]),
+            Stage::Combinator(..) => (
+                quote! { ::risten::listeners::PassThrough::<#event_type>::new() },
+                listeners,
+            ),
+        };
+        for expr in rest {
+            pipeline = match stage(expr) {
+                Stage::Combinator(ident, arg) => {
+                    quote_spanned! {expr.span()=> #pipeline.#ident(#arg) }
+                }
+                Stage::Listener(listener) => {
+                    quote_spanned! {expr.span()=> #pipeline.and_then(#listener) }
+                }
+            };
+        }
+        if let Stage::Combinator(ident, _) = stage(handler) {
+            return Err(syn::Error::new(
+                ident.span(),
+                format!("the last stage must be a handler, found `{}(..)`", ident),
+            ));
+        }
+        hooks.push(quote_spanned! {span=> __route(#pipeline.handler(#handler)) });
+    }
+
+    Ok(quote! {
+        {
+            #[allow(unused_imports)]
+            use ::risten::Listener as _;
+
+            // Checks each route where it is written, for errors on the right span
+            fn __route<H: ::risten::Hook<#event_type>>(hook: H) -> H {
+                hook
+            }
+
+            ::risten::StaticRouter::new(::risten::static_hooks![#(#hooks),*])
+        }
+    })
 }
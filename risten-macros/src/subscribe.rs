@@ -5,8 +5,14 @@
 //! - `#[on]` - Alias for `#[subscribe]`
 
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{FnArg, Ident, ItemFn, LitInt, Token, Type, parse::Parse, parse_macro_input};
+use quote::{quote, quote_spanned};
+use syn::{
+    Expr, FnArg, Ident, ItemFn, LitInt, LitStr, PatType, Path, ReturnType, Token, Type,
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    punctuated::Punctuated,
+    spanned::Spanned,
+};
 
 /// Arguments for the `#[subscribe]` macro.
 pub(crate) struct SubscribeArgs {
@@ -14,23 +20,81 @@ pub(crate) struct SubscribeArgs {
     pub event_type: Option<Type>,
     /// Priority for handler execution (higher = earlier).
     pub priority: i32,
+    /// Predicate on `&Event` evaluated before extraction.
+    pub filter: Option<Expr>,
+    /// Name stored in the registration (defaults to the function name).
+    pub name: Option<LitStr>,
+    /// Tags stored in the registration.
+    pub tags: Vec<LitStr>,
+    /// Disable the handler after its first successful run.
+    pub once: bool,
+    /// Per-attempt timeout in milliseconds.
+    pub timeout: Option<u64>,
+    /// Additional attempts after a failure.
+    pub retries: Option<u32>,
+    /// Delay before the first retry in milliseconds.
+    pub backoff: Option<u64>,
+    /// Maximum number of concurrent runs.
+    pub concurrency: Option<usize>,
+    /// Run the handler in its own tracing span.
+    pub instrument: bool,
+}
+
+/// Parses a duration literal such as `"500ms"`, `"5s"`, `"2m"` or `"1h"` into milliseconds.
+pub(crate) fn parse_duration_millis(lit: &LitStr) -> syn::Result<u64> {
+    let value = lit.value();
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (amount, unit) = value.split_at(split);
+    let scale = match unit.trim() {
+        "ms" => 1,
+        "s" => 1_000,
+        "m" => 60_000,
+        "h" => 3_600_000,
+        _ => 0,
+    };
+    match amount.parse::<u64>() {
+        Ok(amount) if scale > 0 => Ok(amount * scale),
+        _ => Err(syn::Error::new(
+            lit.span(),
+            "expected a duration such as \"500ms\", \"5s\", \"2m\" or \"1h\"",
+        )),
+    }
+}
+
+/// Delay before the first retry when `backoff` is not given.
+const DEFAULT_BACKOFF_MILLIS: u64 = 100;
+
+/// Whether the next argument is a bare flag named `flag`.
+fn peek_flag(input: ParseStream, flag: &str) -> bool {
+    let fork = input.fork();
+    match fork.parse::<Ident>() {
+        Ok(ident) => ident == flag && (fork.is_empty() || fork.peek(Token![,])),
+        Err(_) => false,
+    }
 }
 
 impl Parse for SubscribeArgs {
-    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
         let mut event_type = None;
         let mut priority = 0;
-
-        // Check if we have named arguments or just a type
-        if input.is_empty() {
-            return Ok(SubscribeArgs {
-                event_type: None,
-                priority: 0,
-            });
-        }
+        let mut filter = None;
+        let mut name = None;
+        let mut tags = Vec::new();
+        let mut once = false;
+        let mut timeout = None;
+        let mut retries = None;
+        let mut backoff = None;
+        let mut concurrency = None;
+        let mut instrument = false;
 
         // Try to parse as just a type first
-        if input.peek(Ident) && !input.peek2(Token![=]) {
+        if input.peek(Ident)
+            && !input.peek2(Token![=])
+            && !peek_flag(input, "once")
+            && !peek_flag(input, "instrument")
+        {
             // This looks like a type, not a named arg
             event_type = Some(input.parse()?);
         }
@@ -46,6 +110,14 @@ impl Parse for SubscribeArgs {
             }
 
             let ident: Ident = input.parse()?;
+            if ident == "once" {
+                once = true;
+                continue;
+            }
+            if ident == "instrument" {
+                instrument = true;
+                continue;
+            }
             input.parse::<Token![=]>()?;
 
             match ident.to_string().as_str() {
@@ -53,6 +125,40 @@ impl Parse for SubscribeArgs {
                     let lit: LitInt = input.parse()?;
                     priority = lit.base10_parse()?;
                 }
+                "filter" => {
+                    filter = Some(input.parse()?);
+                }
+                "name" => {
+                    name = Some(input.parse()?);
+                }
+                "timeout" => {
+                    timeout = Some(parse_duration_millis(&input.parse()?)?);
+                }
+                "retries" => {
+                    let lit: LitInt = input.parse()?;
+                    retries = Some(lit.base10_parse()?);
+                }
+                "backoff" => {
+                    backoff = Some(parse_duration_millis(&input.parse()?)?);
+                }
+                "concurrency" => {
+                    let lit: LitInt = input.parse()?;
+                    let limit: usize = lit.base10_parse()?;
+                    if limit == 0 {
+                        return Err(syn::Error::new(
+                            lit.span(),
+                            "concurrency must be at least 1",
+                        ));
+                    }
+                    concurrency = Some(limit);
+                }
+                "tags" => {
+                    let content;
+                    syn::bracketed!(content in input);
+                    tags = Punctuated::<LitStr, Token![,]>::parse_terminated(&content)?
+                        .into_iter()
+                        .collect();
+                }
                 other => {
                     return Err(syn::Error::new(
                         ident.span(),
@@ -65,15 +171,181 @@ impl Parse for SubscribeArgs {
         Ok(SubscribeArgs {
             event_type,
             priority,
+            filter,
+            name,
+            tags,
+            once,
+            timeout,
+            retries,
+            backoff,
+            concurrency,
+            instrument,
         })
     }
 }
 
+/// Returns the referenced type `T` if `ty` is `&T`.
+pub(crate) fn referenced_type(ty: &Type) -> Option<&Type> {
+    match ty {
+        Type::Reference(type_ref) => Some(&type_ref.elem),
+        _ => None,
+    }
+}
+
+/// Splits `Enum::Variant` into the enum path and the variant name.
+///
+/// A path is treated as a variant when its second-to-last segment is
+/// `UpperCamelCase` (a type), as opposed to a module path like `events::MyEvent`.
+pub(crate) fn variant_path(ty: &Type) -> Option<(Path, &Ident)> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    if type_path.qself.is_some() {
+        return None;
+    }
+    let segments = &type_path.path.segments;
+    let count = segments.len();
+    if count < 2 {
+        return None;
+    }
+    let parent = segments[count - 2].ident.to_string();
+    if !parent.starts_with(|c: char| c.is_ascii_uppercase()) {
+        return None;
+    }
+    let enum_path = Path {
+        leading_colon: type_path.path.leading_colon,
+        segments: segments.iter().take(count - 1).cloned().collect(),
+    };
+    Some((enum_path, &segments[count - 1].ident))
+}
+
+/// Returns the extractor `X` of an argument marked `#[extract(X)]`.
+pub(crate) fn extractor_attr(arg: &PatType) -> syn::Result<Option<Type>> {
+    arg.attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("extract"))
+        .map(|attr| attr.parse_args())
+        .transpose()
+}
+
+/// Generates the binding of argument `i` of a handler of `event_type`.
+///
+/// An argument marked `#[extract(X)]` is projected via `X: FromEventGat<E>`.
+/// Otherwise `&E` borrows the event held in `__event`, `&T` borrows a
+/// projection via `T: FromEventGat<E>` and any other `T` is extracted via
+/// `AsyncFromEvent<E>`.
+pub(crate) fn extraction_binding(
+    i: usize,
+    ty: &Type,
+    extractor: Option<&Type>,
+    event_type: &Type,
+    arg_name: &Ident,
+) -> proc_macro2::TokenStream {
+    let event_type_str = quote!(#event_type).to_string();
+    let projection = match (extractor, referenced_type(ty)) {
+        (Some(extractor), _) => Some(extractor),
+        // `&E` borrows the event itself
+        (None, Some(inner)) if quote!(#inner).to_string() == event_type_str => {
+            return quote! {
+                let #arg_name: #ty = &__event;
+            };
+        }
+        (None, inner) => inner,
+    };
+    match projection {
+        // Borrows a projection of the event via `X: FromEventGat<E>`
+        Some(extractor) => quote! {
+            let #arg_name: #ty = <#extractor as ::risten::FromEventGat<#event_type>>::extract(&__event)
+                .map_err(|e| {
+                    ::risten::ExtractError::rejected::<#extractor>(
+                        #i,
+                        <#extractor as ::risten::FromEventGat<#event_type>>::rejection(e),
+                    )
+                })?;
+        },
+        None => quote! {
+            let #arg_name: #ty = <#ty as ::risten::AsyncFromEvent<#event_type>>::from_event(&__event)
+                .await
+                .map_err(|e| {
+                    ::risten::ExtractError::rejected::<#ty>(
+                        #i,
+                        <#ty as ::risten::AsyncFromEvent<#event_type>>::rejection(e),
+                    )
+                })?;
+        },
+    }
+}
+
+/// Generates the check of a `filter` predicate against `&__event`.
+pub(crate) fn filter_check(filter: &Expr) -> proc_macro2::TokenStream {
+    quote! {
+        if !(#filter)(&__event) {
+            return ::core::result::Result::Err(::risten::ExtractError::skipped(
+                concat!("filter `", stringify!(#filter), "` rejected the event"),
+            ));
+        }
+    }
+}
+
+/// Wraps a handler in the wrappers requested by `args`.
+///
+/// Wrappers apply from the inside out: timeout per attempt, retries, the
+/// concurrency limit across attempts, the `instrument` span named `name`,
+/// then `once`. Returns the wrapped type and the expression constructing it.
+pub(crate) fn wrap_handler(
+    args: &SubscribeArgs,
+    name: &proc_macro2::TokenStream,
+    mut handler_type: proc_macro2::TokenStream,
+    mut handler_value: proc_macro2::TokenStream,
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    if let Some(millis) = args.timeout {
+        handler_type = quote! { ::risten::hooks::timeout::TimeoutHook<#handler_type> };
+        handler_value = quote! {
+            ::risten::hooks::timeout::TimeoutHook::new(
+                #handler_value,
+                ::core::time::Duration::from_millis(#millis),
+            )
+        };
+    }
+    if let Some(retries) = args.retries {
+        let backoff = args.backoff.unwrap_or(DEFAULT_BACKOFF_MILLIS);
+        handler_type = quote! { ::risten::hooks::retry::RetryHook<#handler_type> };
+        handler_value = quote! {
+            ::risten::hooks::retry::RetryHook::new(
+                #handler_value,
+                #retries,
+                ::core::time::Duration::from_millis(#backoff),
+            )
+        };
+    }
+    if let Some(limit) = args.concurrency {
+        handler_type = quote! { ::risten::hooks::concurrency::ConcurrencyLimitHook<#handler_type> };
+        handler_value = quote! {
+            ::risten::hooks::concurrency::ConcurrencyLimitHook::new(#handler_value, #limit)
+        };
+    }
+    if args.instrument {
+        handler_type = quote! { ::risten::hooks::instrument::InstrumentHook<#handler_type> };
+        handler_value = quote! {
+            ::risten::hooks::instrument::InstrumentHook::new(#handler_value, #name)
+        };
+    }
+    if args.once {
+        handler_type = quote! { ::risten::routing::OnceHandler<#handler_type> };
+        handler_value = quote! { ::risten::routing::OnceHandler::new(#handler_value) };
+    }
+    (handler_type, handler_value)
+}
+
 /// Generates a handler that wraps user function to return `Result<(), ExtractError>`.
+///
+/// A `filter` predicate is checked against `&Event` before any extraction;
+/// events it rejects are skipped as a soft rejection.
 pub(crate) fn generate_subscribe_handler_impl(
     input: &ItemFn,
     event_type: Option<&Type>,
-) -> (proc_macro2::TokenStream, Type) {
+    filter: Option<&Expr>,
+) -> syn::Result<(proc_macro2::TokenStream, Type)> {
     let fn_name = &input.sig.ident;
     let fn_vis = &input.vis;
     let fn_block = &input.block;
@@ -83,12 +355,18 @@ pub(crate) fn generate_subscribe_handler_impl(
 
     let struct_name = fn_name.clone();
 
-    // Determine event type from first argument or explicit type
+    // Determine event type from first argument (`E` or `&E`) or explicit type
     let inferred_event_type = match event_type {
         Some(ty) => quote! { #ty },
         None => match inputs.first() {
             Some(FnArg::Typed(pat_type)) => {
-                let ty = &pat_type.ty;
+                if extractor_attr(pat_type)?.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        pat_type,
+                        "subscribe function starting with an #[extract] argument must specify event type",
+                    ));
+                }
+                let ty = referenced_type(&pat_type.ty).unwrap_or(&pat_type.ty);
                 quote! { #ty }
             }
             _ => panic!("subscribe function must have at least one argument or specify event type"),
@@ -97,6 +375,24 @@ pub(crate) fn generate_subscribe_handler_impl(
 
     let parsed_event_type: Type = syn::parse2(inferred_event_type.clone()).unwrap();
 
+    let filter_check = filter.map(filter_check);
+
+    // A `Result<(), E>` return turns `Err` into a hard failure of the handler
+    let fallible_ret = match &input.sig.output {
+        ReturnType::Default => None,
+        ReturnType::Type(_, ty) => Some(ty),
+    };
+    let asyncness = &input.sig.asyncness;
+    let await_inner = is_async.then(|| quote! { .await });
+    let finish_inner = |call: proc_macro2::TokenStream| match fallible_ret {
+        Some(_) => quote! { #call.map_err(::risten::ExtractError::failed) },
+        None => quote! {
+            #call;
+            ::core::result::Result::Ok(())
+        },
+    };
+    let ret_sig = fallible_ret.map(|ty| quote! { -> #ty });
+
     // For single-argument handlers, use simpler code path
     if arg_count == 1 && event_type.is_none() {
         let (input_pat, input_type) = match inputs.first().unwrap() {
@@ -104,7 +400,19 @@ pub(crate) fn generate_subscribe_handler_impl(
             _ => panic!("subscribe function must take at least one argument"),
         };
 
-        let call_body = if is_async {
+        // A borrowed event argument (`&E`) borrows the owned event
+        let event_binding = match referenced_type(input_type) {
+            Some(_) => quote! { &__event },
+            None => quote! { __event },
+        };
+
+        let call_body = if fallible_ret.is_some() {
+            let finish = finish_inner(quote! { __inner(#event_binding) #await_inner });
+            quote! {
+                #asyncness fn __inner(#input_pat: #input_type) #ret_sig #fn_block
+                #finish
+            }
+        } else if is_async {
             quote! {
                 #fn_block
                 ::core::result::Result::Ok(())
@@ -116,22 +424,35 @@ pub(crate) fn generate_subscribe_handler_impl(
             }
         };
 
+        let input_binding = fallible_ret
+            .is_none()
+            .then(|| quote! { let #input_pat: #input_type = #event_binding; });
+        let call_fn = quote! {
+            async fn call(&self, __event: #inferred_event_type) -> Self::Output {
+                #filter_check
+                #input_binding
+                #call_body
+            }
+        };
+
         let impl_code = quote! {
             #[allow(non_camel_case_types)]
             #[derive(Clone, Copy, Debug, Default)]
             #[doc = concat!("Auto-generated Handler from `#[risten::subscribe]` on `", stringify!(#fn_name), "`")]
             #fn_vis struct #struct_name;
 
-            impl ::risten::Handler<#input_type> for #struct_name {
+            impl ::risten::Handler<#inferred_event_type> for #struct_name {
                 type Output = ::core::result::Result<(), ::risten::ExtractError>;
 
-                async fn call(&self, #input_pat: #input_type) -> Self::Output {
-                    #call_body
+                #call_fn
+
+                fn name(&self) -> ::std::borrow::Cow<'static, str> {
+                    ::std::borrow::Cow::Borrowed(stringify!(#fn_name))
                 }
             }
         };
 
-        return (impl_code, *input_type.clone());
+        return Ok((impl_code, parsed_event_type));
     }
 
     // Multi-argument handlers need extraction
@@ -149,11 +470,10 @@ pub(crate) fn generate_subscribe_handler_impl(
                 arg_pats.push(quote! { #pat });
                 arg_types.push(quote! { #ty });
 
-                extraction_code.push(quote! {
-                    let #arg_name: #ty = <#ty as ::risten::AsyncFromEvent<_>>::from_event(&__event)
-                        .await
-                        .map_err(|e| ::risten::ExtractError::new(e.to_string()))?;
-                });
+                let extractor = extractor_attr(pat_type)?;
+                let binding =
+                    extraction_binding(i, ty, extractor.as_ref(), &parsed_event_type, &arg_name);
+                extraction_code.push(binding);
             }
             FnArg::Receiver(_) => panic!("subscribe handler cannot have self parameter"),
         }
@@ -163,22 +483,10 @@ pub(crate) fn generate_subscribe_handler_impl(
         .map(|i| Ident::new(&format!("__arg_{}", i), fn_name.span()))
         .collect();
 
-    let inner_call = if is_async {
-        quote! {
-            async fn __inner(#(#arg_pats: #arg_types),*) {
-                #fn_block
-            }
-            __inner(#(#arg_names),*).await;
-            ::core::result::Result::Ok(())
-        }
-    } else {
-        quote! {
-            fn __inner(#(#arg_pats: #arg_types),*) {
-                #fn_block
-            }
-            __inner(#(#arg_names),*);
-            ::core::result::Result::Ok(())
-        }
+    let finish = finish_inner(quote! { __inner(#(#arg_names),*) #await_inner });
+    let inner_call = quote! {
+        #asyncness fn __inner(#(#arg_pats: #arg_types),*) #ret_sig #fn_block
+        #finish
     };
 
     let impl_code = quote! {
@@ -191,13 +499,18 @@ pub(crate) fn generate_subscribe_handler_impl(
             type Output = ::core::result::Result<(), ::risten::ExtractError>;
 
             async fn call(&self, __event: #inferred_event_type) -> Self::Output {
+                #filter_check
                 #(#extraction_code)*
                 #inner_call
             }
+
+            fn name(&self) -> ::std::borrow::Cow<'static, str> {
+                ::std::borrow::Cow::Borrowed(stringify!(#fn_name))
+            }
         }
     };
 
-    (impl_code, parsed_event_type)
+    Ok((impl_code, parsed_event_type))
 }
 
 /// Subscribe a function to handle events of a specific type.
@@ -231,6 +544,41 @@ pub(crate) fn generate_subscribe_handler_impl(
 /// async fn with_context(event: MessageEvent, user: UserContext) {
 ///     // user is extracted via AsyncFromEvent
 /// }
+///
+/// // With borrowed arguments (`&T` is extracted via `T: FromEventGat<E>`)
+/// #[risten::subscribe]
+/// async fn zero_copy(event: &MessageEvent, content: &str) {
+///     // content borrows from the event
+/// }
+///
+/// // Only the `Message` variant of an enum event, receiving its payload
+/// #[risten::subscribe(AppEvent::Message)]
+/// async fn on_app_message(event: MessageEvent) {
+///     // ...
+/// }
+///
+/// // Filtered before extraction, named and tagged for introspection,
+/// // and disabled after its first successful run
+/// #[risten::subscribe(filter = is_admin, name = "bootstrap", tags = ["admin"], once)]
+/// async fn bootstrap(event: MessageEvent) {
+///     // ...
+/// }
+///
+/// // Each attempt times out after 5s, failures (including `Err` returns) are
+/// // retried up to 3 times with exponential backoff, and at most one run is
+/// // in flight at a time. `timeout` needs the `timeout` feature; `retries`
+/// // and `concurrency` need the `resilience` feature.
+/// #[risten::subscribe(timeout = "5s", retries = 3, backoff = "200ms", concurrency = 1)]
+/// async fn sync_profile(event: MessageEvent) -> Result<(), ProfileError> {
+///     // ...
+/// }
+///
+/// // Runs in its own `risten.instrument` span, logging failures
+/// // (needs the `tracing` feature)
+/// #[risten::subscribe(instrument)]
+/// async fn audited(event: MessageEvent) -> Result<(), AuditError> {
+///     // ...
+/// }
 /// ```
 pub fn subscribe_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
     let args = parse_macro_input!(attr as SubscribeArgs);
@@ -255,10 +603,42 @@ pub fn subscribe_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
         .into();
     }
 
+    // `#[subscribe(Enum::Variant)]` handles the variant's payload
+    let variant = args.event_type.as_ref().and_then(variant_path);
+    let explicit_type = match variant {
+        Some(_) => None,
+        None => args.event_type.as_ref(),
+    };
+
     let (handler_impl, event_type) =
-        generate_subscribe_handler_impl(&input, args.event_type.as_ref());
+        match generate_subscribe_handler_impl(&input, explicit_type, args.filter.as_ref()) {
+            Ok(generated) => generated,
+            Err(err) => return err.to_compile_error().into(),
+        };
     let handler_struct_name = fn_name;
 
+    let registration_name = match &args.name {
+        Some(name) => quote! { #name },
+        None => quote! { stringify!(#fn_name) },
+    };
+
+    let (handler_type, handler_value) = wrap_handler(
+        &args,
+        &registration_name,
+        quote! { #handler_struct_name },
+        quote! { #handler_struct_name },
+    );
+    let tags = &args.tags;
+    let registration_meta = quote! {
+        .with_name(#registration_name)
+        .with_tags(&[#(#tags),*])
+        .with_location(::risten::routing::SourceLocation {
+            module_path: ::core::module_path!(),
+            file: ::core::file!(),
+            line: ::core::line!(),
+        })
+    };
+
     let static_name = Ident::new(
         &format!("__HANDLER_INSTANCE_{}", fn_name).to_uppercase(),
         fn_name.span(),
@@ -268,21 +648,66 @@ pub fn subscribe_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
         fn_name.span(),
     );
 
-    let submit_code = quote! {
-        #[allow(non_upper_case_globals)]
-        static #static_name: #handler_struct_name = #handler_struct_name;
+    let submit_code = match variant {
+        Some((enum_path, variant_name)) => {
+            let project_name = Ident::new(&format!("__project_{}", fn_name), fn_name.span());
+            // Variant subscriptions need `Message::variant`, as derived on enums
+            let assert_variants = quote_spanned! {enum_path.span()=>
+                const _: fn() = {
+                    fn assert_variants<T: ::risten::VariantMessage>() {}
+                    assert_variants::<#enum_path>
+                };
+            };
+            quote! {
+                #assert_variants
+
+                #[allow(non_upper_case_globals)]
+                static #static_name: #handler_struct_name = #handler_struct_name;
 
-        #[allow(non_upper_case_globals)]
-        static #wrapper_name: ::risten::routing::ErasedHandlerWrapper<#event_type, #handler_struct_name> =
-            ::risten::routing::ErasedHandlerWrapper::new(#handler_struct_name);
+                #[allow(unreachable_patterns)]
+                fn #project_name(event: #enum_path) -> ::core::option::Option<#event_type> {
+                    match event {
+                        #enum_path::#variant_name(payload) => ::core::option::Option::Some(payload),
+                        _ => ::core::option::Option::None,
+                    }
+                }
 
-        ::risten::inventory::submit! {
-            ::risten::routing::HandlerRegistration {
-                type_id: ::std::any::TypeId::of::<#event_type>(),
-                handler: &#wrapper_name,
-                priority: #priority,
+                #[allow(non_upper_case_globals)]
+                static #wrapper_name: ::risten::routing::ErasedHandlerWrapper<
+                    #enum_path,
+                    ::risten::routing::VariantHandler<#enum_path, #event_type, #handler_type>,
+                > = ::risten::routing::ErasedHandlerWrapper::new(
+                    ::risten::routing::VariantHandler::new(#handler_value, #project_name),
+                );
+
+                ::risten::inventory::submit! {
+                    ::risten::routing::HandlerRegistration::new(
+                        ::std::any::TypeId::of::<#enum_path>(),
+                        &#wrapper_name,
+                        #priority,
+                    )
+                    .with_variant(stringify!(#variant_name))
+                    #registration_meta
+                }
             }
         }
+        None => quote! {
+            #[allow(non_upper_case_globals)]
+            static #static_name: #handler_struct_name = #handler_struct_name;
+
+            #[allow(non_upper_case_globals)]
+            static #wrapper_name: ::risten::routing::ErasedHandlerWrapper<#event_type, #handler_type> =
+                ::risten::routing::ErasedHandlerWrapper::new(#handler_value);
+
+            ::risten::inventory::submit! {
+                ::risten::routing::HandlerRegistration::new(
+                    ::std::any::TypeId::of::<#event_type>(),
+                    &#wrapper_name,
+                    #priority,
+                )
+                #registration_meta
+            }
+        },
     };
 
     let expanded = quote! {
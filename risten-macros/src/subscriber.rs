@@ -0,0 +1,274 @@
+//! Subscriber-related macros.
+//!
+//! This module contains:
+//! - `#[subscriber]` - Attribute macro turning `#[on]` methods of an impl block into subscriptions
+
+use crate::subscribe::{
+    SubscribeArgs, extraction_binding, extractor_attr, filter_check, referenced_type, variant_path,
+    wrap_handler,
+};
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{FnArg, ImplItem, ImplItemFn, ItemImpl, PatType, ReturnType, Type, parse_macro_input};
+
+/// Implementation of the `#[subscriber]` attribute macro.
+///
+/// Every `#[on]` method of the impl block becomes a handler holding an
+/// `Arc` of the instance, and the type implements `Subscriber` to register
+/// them all at runtime.
+pub fn subscriber_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as ItemImpl);
+
+    if !attr.is_empty() {
+        return syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "#[subscriber] takes no arguments; configure each method with #[on(...)]",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    match expand_subscriber(input) {
+        Ok(expanded) => TokenStream::from(expanded),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand_subscriber(mut input: ItemImpl) -> syn::Result<proc_macro2::TokenStream> {
+    if let Some((_, path, _)) = &input.trait_ {
+        return Err(syn::Error::new_spanned(
+            path,
+            "#[subscriber] must be used on an inherent impl block",
+        ));
+    }
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "#[subscriber] does not support generic impl blocks",
+        ));
+    }
+
+    let self_ty = input.self_ty.clone();
+    let type_name = match &*self_ty {
+        Type::Path(type_path) if type_path.qself.is_none() => {
+            type_path.path.segments.last().unwrap().ident.clone()
+        }
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &self_ty,
+                "#[subscriber] must be used on an impl block of a named type",
+            ));
+        }
+    };
+
+    let mut handlers = Vec::new();
+    let mut registrations = Vec::new();
+
+    for item in &mut input.items {
+        let ImplItem::Fn(method) = item else {
+            continue;
+        };
+        let Some(position) = method
+            .attrs
+            .iter()
+            .position(|attr| attr.path().is_ident("on"))
+        else {
+            continue;
+        };
+        // `#[on]` is only meaningful to this macro
+        let attr = method.attrs.remove(position);
+        let args: SubscribeArgs = match &attr.meta {
+            syn::Meta::Path(_) => syn::parse_quote!(),
+            _ => attr.parse_args()?,
+        };
+
+        let (handler, registration) = expand_method(&self_ty, &type_name, method, &args)?;
+        handlers.push(handler);
+        registrations.push(registration);
+
+        // `#[extract]` is only meaningful to this macro
+        for arg in &mut method.sig.inputs {
+            if let FnArg::Typed(pat_type) = arg {
+                pat_type
+                    .attrs
+                    .retain(|attr| !attr.path().is_ident("extract"));
+            }
+        }
+    }
+
+    Ok(quote! {
+        #input
+
+        #(#handlers)*
+
+        impl ::risten::routing::Subscriber for #self_ty {
+            fn subscribe(self: ::std::sync::Arc<Self>) -> ::risten::routing::Subscription {
+                let mut __subscription = ::risten::routing::Subscription::new();
+                #(#registrations)*
+                __subscription
+            }
+        }
+    })
+}
+
+/// Generates the handler of one `#[on]` method and its registration.
+fn expand_method(
+    self_ty: &Type,
+    type_name: &syn::Ident,
+    method: &ImplItemFn,
+    args: &SubscribeArgs,
+) -> syn::Result<(proc_macro2::TokenStream, proc_macro2::TokenStream)> {
+    let method_name = &method.sig.ident;
+    let sig = &method.sig;
+
+    if sig.asyncness.is_none() {
+        return Err(syn::Error::new_spanned(
+            sig.fn_token,
+            "#[on] method must be async",
+        ));
+    }
+    if !sig.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &sig.generics,
+            "#[on] method cannot be generic",
+        ));
+    }
+    match sig.inputs.first() {
+        Some(FnArg::Receiver(receiver))
+            if receiver.reference.is_some() && receiver.mutability.is_none() => {}
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &sig.inputs,
+                "#[on] method must take `&self`",
+            ));
+        }
+    }
+    if let Some(name) = &args.name {
+        return Err(syn::Error::new_spanned(
+            name,
+            "`name` is not supported on #[on] methods",
+        ));
+    }
+    if let Some(tag) = args.tags.first() {
+        return Err(syn::Error::new_spanned(
+            tag,
+            "`tags` are not supported on #[on] methods",
+        ));
+    }
+    if let Some(ty) = args
+        .event_type
+        .as_ref()
+        .filter(|ty| variant_path(ty).is_some())
+    {
+        return Err(syn::Error::new_spanned(
+            ty,
+            "variant subscriptions are not supported on #[on] methods; subscribe to the enum and match on the variant",
+        ));
+    }
+
+    let typed_args: Vec<&PatType> = sig
+        .inputs
+        .iter()
+        .filter_map(|arg| match arg {
+            FnArg::Typed(pat_type) => Some(pat_type),
+            FnArg::Receiver(_) => None,
+        })
+        .collect();
+    let extractors = typed_args
+        .iter()
+        .map(|arg| extractor_attr(arg))
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    // The event type is explicit, or taken from the first argument (`E` or `&E`)
+    let event_type = match (&args.event_type, typed_args.first()) {
+        (Some(ty), _) => ty.clone(),
+        (None, Some(arg)) if extractors[0].is_some() => {
+            return Err(syn::Error::new_spanned(
+                arg,
+                "#[on] method starting with an #[extract] argument must name the event type: #[on(Event)]",
+            ));
+        }
+        (None, Some(arg)) => referenced_type(&arg.ty).unwrap_or(&arg.ty).clone(),
+        (None, None) => {
+            return Err(syn::Error::new_spanned(
+                sig,
+                "#[on] method must take the event, or name its type: #[on(Event)]",
+            ));
+        }
+    };
+    let event_type_str = quote!(#event_type).to_string();
+
+    let arg_names: Vec<_> = (0..typed_args.len())
+        .map(|i| format_ident!("__arg_{}", i))
+        .collect();
+    let mut extraction_code = Vec::new();
+    let mut event_binding = None;
+    for (i, ((arg, extractor), arg_name)) in typed_args
+        .iter()
+        .zip(&extractors)
+        .zip(&arg_names)
+        .enumerate()
+    {
+        let ty = &arg.ty;
+        // An owned event is moved once everything else is extracted
+        if extractor.is_none() && quote!(#ty).to_string() == event_type_str {
+            event_binding = Some(quote! { let #arg_name: #ty = __event; });
+            continue;
+        }
+        extraction_code.push(extraction_binding(
+            i,
+            ty,
+            extractor.as_ref(),
+            &event_type,
+            arg_name,
+        ));
+    }
+
+    let filter = args.filter.as_ref().map(filter_check);
+    let call = quote! { <#self_ty>::#method_name(&self.0, #(#arg_names),*).await };
+    let finish = match &sig.output {
+        ReturnType::Default => quote! {
+            #call;
+            ::core::result::Result::Ok(())
+        },
+        ReturnType::Type(..) => quote! { #call.map_err(::risten::ExtractError::failed) },
+    };
+
+    let handler_name = format_ident!("__{}_{}_handler", type_name, method_name);
+    let handler = quote! {
+        #[allow(non_camel_case_types)]
+        #[doc = concat!("Handler for `", stringify!(#type_name), "::", stringify!(#method_name), "`, generated by `#[risten::subscriber]`.")]
+        struct #handler_name(::std::sync::Arc<#self_ty>);
+
+        impl ::risten::Handler<#event_type> for #handler_name {
+            type Output = ::core::result::Result<(), ::risten::ExtractError>;
+
+            async fn call(&self, __event: #event_type) -> Self::Output {
+                #filter
+                #(#extraction_code)*
+                #event_binding
+                #finish
+            }
+
+            fn name(&self) -> ::std::borrow::Cow<'static, str> {
+                ::std::borrow::Cow::Borrowed(concat!(stringify!(#type_name), "::", stringify!(#method_name)))
+            }
+        }
+    };
+
+    let (_, handler_value) = wrap_handler(
+        args,
+        &quote! { concat!(stringify!(#type_name), "::", stringify!(#method_name)) },
+        quote! { #handler_name },
+        quote! { #handler_name(::std::sync::Arc::clone(&self)) },
+    );
+    let priority = args.priority;
+    let registration = quote! {
+        __subscription.merge(::risten::DispatchRouter::<#event_type>::register(
+            #handler_value,
+            #priority,
+        ));
+    };
+
+    Ok((handler, registration))
+}
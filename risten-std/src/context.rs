@@ -0,0 +1,157 @@
+//! Task-local dispatch context.
+//!
+//! While a router in this crate routes an event, the event being processed
+//! is described by a [`DispatchContext`], queryable from anywhere in the
+//! handling task with [`current`]: repositories, HTTP clients and loggers
+//! deep inside a handler can tell which event they are working for.
+//!
+//! The context is preserved across `.await` points. Spawned tasks do not
+//! inherit it unless they opt in with [`propagate`]:
+//!
+//! ```rust,ignore
+//! tokio::spawn(risten::context::propagate(async move {
+//!     let context = risten::context::current().unwrap();
+//!     tracing::info!(event = context.event_type(), "sending email");
+//! }));
+//! ```
+//!
+//! Routing an [`Envelope`](risten_core::Envelope) that carries a W3C trace
+//! context restores it: the route becomes a child span of the one that
+//! produced the event, available from [`DispatchContext::trace_context`].
+//!
+//! Custom routers set the context with [`in_route`] and [`in_hook`].
+
+use crate::task_local::{self, Scoped};
+use pin_project_lite::pin_project;
+use risten_core::{EventId, Message, Metadata, TraceContext};
+use std::{
+    borrow::Cow,
+    cell::RefCell,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+thread_local! {
+    static CURRENT: RefCell<Option<DispatchContext>> = const { RefCell::new(None) };
+}
+
+/// Describes the event being routed by the current task.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DispatchContext {
+    event_type: &'static str,
+    event_id: Option<EventId>,
+    hook: Option<Cow<'static, str>>,
+    depth: usize,
+    trace: Option<TraceContext>,
+    trace_parent: Option<u64>,
+}
+
+impl DispatchContext {
+    /// The type name of the event being routed.
+    pub fn event_type(&self) -> &'static str {
+        self.event_type
+    }
+
+    /// The id of the event, if it is an [`Envelope`](risten_core::Envelope).
+    pub fn event_id(&self) -> Option<EventId> {
+        self.event_id
+    }
+
+    /// The name of the hook or handler processing the event, if known.
+    pub fn hook(&self) -> Option<&str> {
+        self.hook.as_deref()
+    }
+
+    /// How many routers enclose the current one; `0` for a top-level route.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// The trace context of the current route, if the event is part of a trace.
+    ///
+    /// The route is a new span, child of the span that produced the event
+    /// or, for events without a trace context, of the enclosing route.
+    pub fn trace_context(&self) -> Option<&TraceContext> {
+        self.trace.as_ref()
+    }
+
+    /// The id of the parent span of the current route, if traced.
+    pub fn parent_span_id(&self) -> Option<u64> {
+        self.trace_parent
+    }
+}
+
+/// The dispatch context of the current task, if it is routing an event.
+pub fn current() -> Option<DispatchContext> {
+    task_local::current(&CURRENT)
+}
+
+pin_project! {
+    /// A future running with a [`DispatchContext`].
+    ///
+    /// Created by [`propagate`], [`in_route`] and [`in_hook`].
+    pub struct WithContext<F> {
+        #[pin]
+        inner: Scoped<DispatchContext, F>,
+    }
+}
+
+impl<F: Future> Future for WithContext<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.project().inner.poll(cx)
+    }
+}
+
+pub(crate) fn with_context<F>(context: Option<DispatchContext>, future: F) -> WithContext<F> {
+    WithContext {
+        inner: Scoped::new(&CURRENT, context, future),
+    }
+}
+
+/// Run `future` with the dispatch context of the current task.
+///
+/// Use this for tasks spawned while handling an event that should keep its
+/// context.
+pub fn propagate<F: Future>(future: F) -> WithContext<F> {
+    with_context(current(), future)
+}
+
+/// Run `future` as the routing of `event`, one level deeper than the current route.
+pub fn in_route<E: Message, F: Future>(event: &E, future: F) -> WithContext<F> {
+    with_context(Some(route_context(event)), future)
+}
+
+/// The context of a route of `event` started from the current task.
+pub(crate) fn route_context<E: Message>(event: &E) -> DispatchContext {
+    let parent = current();
+    let event_id = event.metadata().map(Metadata::id);
+    // A nested route of the same event continues the enclosing route's span
+    let same_event = event_id.is_some() && parent.as_ref().and_then(|p| p.event_id) == event_id;
+    let trace = event
+        .metadata()
+        .filter(|_| !same_event)
+        .and_then(Metadata::trace_context)
+        .or_else(|| parent.as_ref().and_then(|p| p.trace.clone()));
+    DispatchContext {
+        event_type: std::any::type_name::<E>(),
+        event_id,
+        hook: None,
+        depth: parent.map_or(0, |parent| parent.depth + 1),
+        trace_parent: trace.as_ref().map(TraceContext::span_id),
+        trace: trace.map(|trace| trace.child()),
+    }
+}
+
+/// Run `future` as the hook `name` of the current route.
+///
+/// Outside of a route, `future` runs without a context.
+pub fn in_hook<F: Future>(name: Option<Cow<'static, str>>, future: F) -> WithContext<F> {
+    let context = current().map(|context| DispatchContext {
+        hook: name,
+        ..context
+    });
+    with_context(context, future)
+}
@@ -3,8 +3,9 @@
 //! This module provides runtime-flexible routing mechanisms.
 //! Use when hook composition is determined at runtime (plugins, config-driven).
 
+use crate::instrument;
 use risten_core::{
-    BoxError, RoutingError, DynHook, HookResult, Listener, Message, RouteResult, Router,
+    BoxError, DynHook, HookResult, Listener, Message, RouteResult, Router, RoutingError,
 };
 
 /// A dynamic router that uses runtime hook resolution.
@@ -35,22 +36,26 @@ where
     type Error = RoutingError;
 
     async fn route(&self, event: &E) -> Result<RouteResult, Self::Error> {
-        let hooks = self.provider.resolve(event);
-        let mut stopped = false;
-        for hook in hooks {
-            match hook.on_event_dyn(event).await {
-                Ok(HookResult::Stop) => {
-                    stopped = true;
-                    break;
+        instrument::route(event, async {
+            let hooks = self.provider.resolve(event);
+            let mut stopped = false;
+            for hook in hooks {
+                let name = hook.name_dyn();
+                match instrument::hook(Some(name.clone()), None, hook.on_event_dyn(event)).await {
+                    Ok(HookResult::Stop) => {
+                        stopped = true;
+                        break;
+                    }
+                    Ok(HookResult::Next) => continue,
+                    Err(source) => return Err(RoutingError::Hook { name, source }),
                 }
-                Ok(HookResult::Next) => continue,
-                Err(e) => return Err(RoutingError::Listener(e)),
             }
-        }
-        Ok(RouteResult {
-            stopped,
-            executed_count: 0, // Dynamic router doesn't track count
+            Ok(RouteResult {
+                stopped,
+                executed_count: 0, // Dynamic router doesn't track count
+            })
         })
+        .await
     }
 }
 
@@ -0,0 +1,73 @@
+//! Concurrency limit hook.
+
+use risten_core::{BoxError, Handler, Hook, HookError, HookResult, Message};
+use std::borrow::Cow;
+use tokio::sync::{AcquireError, Semaphore, SemaphorePermit};
+
+/// A hook that limits how many events the wrapped hook processes at once.
+///
+/// Events beyond the limit wait for a running one to finish.
+pub struct ConcurrencyLimitHook<H> {
+    inner: H,
+    semaphore: Semaphore,
+}
+
+impl<H> ConcurrencyLimitHook<H> {
+    /// Create a new hook allowing at most `limit` concurrent calls.
+    pub const fn new(inner: H, limit: usize) -> Self {
+        Self {
+            inner,
+            semaphore: Semaphore::const_new(limit),
+        }
+    }
+
+    /// The number of calls that can currently start without waiting.
+    pub fn available(&self) -> usize {
+        self.semaphore.available_permits()
+    }
+
+    /// Waits for a permit, counted in the queue depth of `E` meanwhile.
+    async fn acquire<E: 'static>(&self) -> Result<SemaphorePermit<'_>, AcquireError> {
+        #[cfg(feature = "metrics")]
+        let _waiting = crate::metrics::GaugeGuard::new(
+            crate::metrics::QUEUE_DEPTH,
+            std::any::type_name::<E>(),
+        );
+        self.semaphore.acquire().await
+    }
+}
+
+impl<E: Message + Sync, H: Hook<E>> Hook<E> for ConcurrencyLimitHook<H> {
+    async fn on_event(&self, event: &E) -> Result<HookResult, BoxError> {
+        // The semaphore is never closed, so acquiring only fails on misuse
+        let _permit = self
+            .acquire::<E>()
+            .await
+            .map_err(|_| HookError::Cancelled)?;
+        self.inner.on_event(event).await
+    }
+
+    fn name(&self) -> Cow<'static, str> {
+        self.inner.name()
+    }
+}
+
+impl<E, H> Handler<E> for ConcurrencyLimitHook<H>
+where
+    E: Message,
+    H: Handler<E>,
+{
+    type Output = H::Output;
+
+    async fn call(&self, input: E) -> Self::Output {
+        let _permit = self
+            .acquire::<E>()
+            .await
+            .expect("ConcurrencyLimitHook never closes its semaphore");
+        self.inner.call(input).await
+    }
+
+    fn name(&self) -> Cow<'static, str> {
+        self.inner.name()
+    }
+}
@@ -0,0 +1,80 @@
+//! Instrumentation hook wrapping execution in a tracing span.
+
+use risten_core::{BoxError, ExtractError, Handler, Hook, HookResult, Message};
+use std::borrow::Cow;
+use tracing::{Instrument, field::Empty};
+
+/// A hook that runs another hook in its own `risten.instrument` span.
+///
+/// Also wraps handlers, which is how `#[subscribe(instrument)]` applies it.
+/// The span records the event type, the hook name and the outcome, and
+/// failures are logged as `error` events inside it.
+pub struct InstrumentHook<H> {
+    inner: H,
+    name: &'static str,
+}
+
+impl<H> InstrumentHook<H> {
+    /// Create a new instrumentation hook named `name`.
+    pub const fn new(inner: H, name: &'static str) -> Self {
+        Self { inner, name }
+    }
+
+    fn span<E>(&self) -> tracing::Span {
+        tracing::info_span!(
+            "risten.instrument",
+            event = std::any::type_name::<E>(),
+            hook = self.name,
+            outcome = Empty,
+        )
+    }
+}
+
+impl<E: Message + Sync, H: Hook<E>> Hook<E> for InstrumentHook<H> {
+    async fn on_event(&self, event: &E) -> Result<HookResult, BoxError> {
+        let span = self.span::<E>();
+        let result = self.inner.on_event(event).instrument(span.clone()).await;
+        let _entered = span.enter();
+        match &result {
+            Ok(HookResult::Next) => span.record("outcome", "next"),
+            Ok(HookResult::Stop) => span.record("outcome", "stop"),
+            Err(err) => {
+                tracing::error!(error = %err, "hook failed");
+                span.record("outcome", "error")
+            }
+        };
+        result
+    }
+
+    fn name(&self) -> Cow<'static, str> {
+        Cow::Borrowed(self.name)
+    }
+}
+
+impl<E, H, T> Handler<E> for InstrumentHook<H>
+where
+    E: Message,
+    H: Handler<E, Output = Result<T, ExtractError>>,
+    T: Send + Sync + 'static,
+{
+    type Output = Result<T, ExtractError>;
+
+    async fn call(&self, input: E) -> Self::Output {
+        let span = self.span::<E>();
+        let result = self.inner.call(input).instrument(span.clone()).await;
+        let _entered = span.enter();
+        match &result {
+            Ok(_) => span.record("outcome", "next"),
+            Err(err) if err.is_rejection() => span.record("outcome", "skipped"),
+            Err(err) => {
+                tracing::error!(error = %err, "handler failed");
+                span.record("outcome", "error")
+            }
+        };
+        result
+    }
+
+    fn name(&self) -> Cow<'static, str> {
+        Cow::Borrowed(self.name)
+    }
+}
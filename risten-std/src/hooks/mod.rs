@@ -1,5 +1,13 @@
 //! Standard hook implementations.
 
+#[cfg(feature = "resilience")]
+pub mod concurrency;
+#[cfg(feature = "tracing")]
+pub mod instrument;
 pub mod logging;
+#[cfg(feature = "opentelemetry")]
+pub mod propagation;
+#[cfg(feature = "resilience")]
+pub mod retry;
 #[cfg(feature = "timeout")]
 pub mod timeout;
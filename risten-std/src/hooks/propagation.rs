@@ -0,0 +1,143 @@
+//! OpenTelemetry trace context propagation.
+//!
+//! [`PropagationHook`] extracts the OpenTelemetry context stored in the
+//! headers of an [`Envelope`](risten_core::Envelope) with the global text map
+//! propagator, and makes it current while the wrapped hook runs: spans
+//! started by the hook become children of the span that produced the event.
+//!
+//! [`inject`] does the opposite for outgoing events, and [`MetadataExtractor`]
+//! and [`MetadataInjector`] let any [`TextMapPropagator`] read and write
+//! envelope headers directly.
+//!
+//! [`TextMapPropagator`]: opentelemetry::propagation::TextMapPropagator
+
+use opentelemetry::{
+    Context, global,
+    propagation::{Extractor, Injector},
+    trace::{FutureExt, SpanContext, SpanId, TraceContextExt, TraceFlags, TraceId},
+};
+use risten_core::{BoxError, Handler, Hook, HookResult, Message, Metadata, TraceContext};
+use std::borrow::Cow;
+
+/// Reads propagation fields from the headers of an event.
+pub struct MetadataExtractor<'a>(pub &'a Metadata);
+
+impl Extractor for MetadataExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.header(key)
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.headers().keys().map(String::as_str).collect()
+    }
+}
+
+/// Writes propagation fields to the headers of an event.
+pub struct MetadataInjector<'a>(pub &'a mut Metadata);
+
+impl Injector for MetadataInjector<'_> {
+    fn set(&mut self, key: &str, value: String) {
+        self.0.insert_header(key, value);
+    }
+}
+
+/// The context propagated in `metadata`, read with the global propagator.
+pub fn extract(metadata: &Metadata) -> Context {
+    global::get_text_map_propagator(|propagator| propagator.extract(&MetadataExtractor(metadata)))
+}
+
+/// Store the current context in `metadata` with the global propagator.
+pub fn inject(metadata: &mut Metadata) {
+    inject_context(&Context::current(), metadata);
+}
+
+/// Store `context` in `metadata` with the global propagator.
+pub fn inject_context(context: &Context, metadata: &mut Metadata) {
+    global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(context, &mut MetadataInjector(metadata));
+    });
+}
+
+/// Convert a W3C trace context into a remote OpenTelemetry span context.
+pub fn to_span_context(trace: &TraceContext) -> SpanContext {
+    let state = trace
+        .tracestate()
+        .and_then(|state| state.parse().ok())
+        .unwrap_or_default();
+    SpanContext::new(
+        TraceId::from(trace.trace_id()),
+        SpanId::from(trace.span_id()),
+        TraceFlags::new(trace.flags()),
+        true,
+        state,
+    )
+}
+
+/// Convert an OpenTelemetry span context into a W3C trace context.
+///
+/// Returns `None` for invalid span contexts.
+pub fn from_span_context(span: &SpanContext) -> Option<TraceContext> {
+    let trace = TraceContext::new(
+        u128::from_be_bytes(span.trace_id().to_bytes()),
+        u64::from_be_bytes(span.span_id().to_bytes()),
+        span.trace_flags().to_u8(),
+    )?;
+    let state = span.trace_state().header();
+    Some(if state.is_empty() {
+        trace
+    } else {
+        trace.with_tracestate(state)
+    })
+}
+
+/// The trace context of the current OpenTelemetry span, if any.
+pub fn current_trace_context() -> Option<TraceContext> {
+    from_span_context(Context::current().span().span_context())
+}
+
+/// A hook that runs another hook in the OpenTelemetry context of the event.
+///
+/// Events without propagated context run in the current context.
+pub struct PropagationHook<H> {
+    inner: H,
+}
+
+impl<H> PropagationHook<H> {
+    /// Create a new propagation hook.
+    pub const fn new(inner: H) -> Self {
+        Self { inner }
+    }
+}
+
+/// The context an event should be handled in.
+fn parent_context<E: Message>(event: &E) -> Context {
+    event
+        .metadata()
+        .map(extract)
+        .filter(|context| context.span().span_context().is_valid())
+        .unwrap_or_else(Context::current)
+}
+
+impl<E: Message + Sync, H: Hook<E>> Hook<E> for PropagationHook<H> {
+    async fn on_event(&self, event: &E) -> Result<HookResult, BoxError> {
+        let context = parent_context(event);
+        self.inner.on_event(event).with_context(context).await
+    }
+
+    fn name(&self) -> Cow<'static, str> {
+        self.inner.name()
+    }
+}
+
+impl<E: Message, H: Handler<E>> Handler<E> for PropagationHook<H> {
+    type Output = H::Output;
+
+    async fn call(&self, input: E) -> Self::Output {
+        let context = parent_context(&input);
+        self.inner.call(input).with_context(context).await
+    }
+
+    fn name(&self) -> Cow<'static, str> {
+        self.inner.name()
+    }
+}
@@ -0,0 +1,84 @@
+//! Retry hook for transient failures.
+
+use risten_core::{BoxError, ExtractError, Handler, Hook, HookResult, Message};
+use std::borrow::Cow;
+use std::time::Duration;
+use tokio::time::sleep;
+
+/// A hook that retries a failing hook with exponential backoff.
+///
+/// The first retry waits `backoff`, and each following one waits twice as
+/// long as the previous. Only hard failures are retried; soft
+/// [`ExtractError`] rejections are returned as-is.
+pub struct RetryHook<H> {
+    inner: H,
+    retries: u32,
+    backoff: Duration,
+}
+
+impl<H> RetryHook<H> {
+    /// Create a new retry hook making up to `retries` additional attempts.
+    pub const fn new(inner: H, retries: u32, backoff: Duration) -> Self {
+        Self {
+            inner,
+            retries,
+            backoff,
+        }
+    }
+
+    fn delay(&self, attempt: u32) -> Duration {
+        self.backoff.saturating_mul(1 << attempt.min(16))
+    }
+}
+
+/// Whether a hook failed with a soft extractor rejection.
+fn is_rejection(error: &(dyn std::error::Error + Send + Sync + 'static)) -> bool {
+    error
+        .downcast_ref::<ExtractError>()
+        .is_some_and(ExtractError::is_rejection)
+}
+
+impl<E: Message + Sync, H: Hook<E>> Hook<E> for RetryHook<H> {
+    async fn on_event(&self, event: &E) -> Result<HookResult, BoxError> {
+        let mut attempt = 0;
+        loop {
+            match self.inner.on_event(event).await {
+                Err(e) if !is_rejection(&*e) && attempt < self.retries => {
+                    sleep(self.delay(attempt)).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    fn name(&self) -> Cow<'static, str> {
+        self.inner.name()
+    }
+}
+
+impl<E, H, T> Handler<E> for RetryHook<H>
+where
+    E: Message + Clone,
+    H: Handler<E, Output = Result<T, ExtractError>>,
+    T: Send + Sync + 'static,
+{
+    type Output = Result<T, ExtractError>;
+
+    async fn call(&self, input: E) -> Self::Output {
+        let mut attempt = 0;
+        loop {
+            match self.inner.call(input.clone()).await {
+                Err(e) if !e.is_rejection() && attempt < self.retries => {
+                    sleep(self.delay(attempt)).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    fn name(&self) -> Cow<'static, str> {
+        self.inner.name()
+    }
+}
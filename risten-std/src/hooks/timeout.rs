@@ -1,22 +1,14 @@
 //! Timeout hook for time-limited execution.
 
-use risten_core::{BoxError, Hook, HookResult, Message};
+use risten_core::{BoxError, ExtractError, Handler, Hook, HookError, HookResult, Message};
+use std::borrow::Cow;
 use std::time::Duration;
 use tokio::time::timeout;
 
-/// Error returned when a hook times out.
-#[derive(Debug, Clone)]
-pub struct TimeoutError;
-
-impl std::fmt::Display for TimeoutError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Hook execution timed out")
-    }
-}
-
-impl std::error::Error for TimeoutError {}
-
 /// A hook that wraps another hook with a timeout.
+///
+/// Also wraps handlers, which is how `#[subscribe(timeout = "5s")]` applies it.
+/// Timeouts fail with [`HookError::Timeout`].
 pub struct TimeoutHook<H> {
     inner: H,
     duration: Duration,
@@ -24,7 +16,7 @@ pub struct TimeoutHook<H> {
 
 impl<H> TimeoutHook<H> {
     /// Create a new timeout hook.
-    pub fn new(inner: H, duration: Duration) -> Self {
+    pub const fn new(inner: H, duration: Duration) -> Self {
         Self { inner, duration }
     }
 }
@@ -33,7 +25,31 @@ impl<E: Message + Sync, H: Hook<E>> Hook<E> for TimeoutHook<H> {
     async fn on_event(&self, event: &E) -> Result<HookResult, BoxError> {
         match timeout(self.duration, self.inner.on_event(event)).await {
             Ok(result) => result,
-            Err(_) => Err(Box::new(TimeoutError)),
+            Err(_) => Err(Box::new(HookError::Timeout(self.duration))),
         }
     }
+
+    fn name(&self) -> Cow<'static, str> {
+        self.inner.name()
+    }
+}
+
+impl<E, H, T> Handler<E> for TimeoutHook<H>
+where
+    E: Message,
+    H: Handler<E, Output = Result<T, ExtractError>>,
+    T: Send + Sync + 'static,
+{
+    type Output = Result<T, ExtractError>;
+
+    async fn call(&self, input: E) -> Self::Output {
+        match timeout(self.duration, self.inner.call(input)).await {
+            Ok(result) => result,
+            Err(_) => Err(ExtractError::failed(HookError::Timeout(self.duration))),
+        }
+    }
+
+    fn name(&self) -> Cow<'static, str> {
+        self.inner.name()
+    }
 }
@@ -0,0 +1,152 @@
+//! Instrumentation of routes and hooks.
+//!
+//! Every router in this crate runs each `route` call through [`route`] and
+//! each hook or handler through [`hook`]. Besides setting the task-local
+//! [`context`], these open tracing spans when the `tracing` feature is
+//! enabled: `risten.route` per route, and a child `risten.hook` per hook,
+//! recording the event type, hook name, priority, outcome and duration.
+//! Route spans of traced events also record the W3C `trace_id`, `span_id`
+//! and `parent_span_id` restored from the event.
+//! With the `metrics` feature, both also report to the current
+//! [`MetricsRecorder`](crate::metrics::MetricsRecorder).
+
+use crate::context;
+use risten_core::{BoxError, ExtractError, HookResult, Message, RouteResult};
+use std::{borrow::Cow, future::Future};
+
+/// How a route or hook finished, as recorded on its span.
+#[cfg_attr(not(any(feature = "tracing", feature = "metrics")), allow(dead_code))]
+pub(crate) trait Outcome {
+    /// `"next"`, `"stop"`, `"skipped"` or `"error"`.
+    fn outcome(&self) -> &'static str;
+}
+
+impl Outcome for Result<HookResult, BoxError> {
+    fn outcome(&self) -> &'static str {
+        match self {
+            Ok(HookResult::Next) => "next",
+            Ok(HookResult::Stop) => "stop",
+            Err(_) => "error",
+        }
+    }
+}
+
+impl Outcome for Result<(), ExtractError> {
+    fn outcome(&self) -> &'static str {
+        match self {
+            Ok(()) => "next",
+            Err(err) if err.is_rejection() => "skipped",
+            Err(_) => "error",
+        }
+    }
+}
+
+impl<E> Outcome for Result<RouteResult, E> {
+    fn outcome(&self) -> &'static str {
+        match self {
+            Ok(result) if result.stopped => "stop",
+            Ok(_) => "next",
+            Err(_) => "error",
+        }
+    }
+}
+
+/// Run `future` as the routing of `event`.
+pub(crate) async fn route<E, F>(event: &E, future: F) -> F::Output
+where
+    E: Message,
+    F: Future,
+    F::Output: Outcome,
+{
+    #[cfg(any(feature = "tracing", feature = "metrics"))]
+    let start = std::time::Instant::now();
+    let route = context::route_context(event);
+
+    #[cfg(feature = "tracing")]
+    let output = {
+        use tracing::{Instrument, field::Empty};
+
+        let span = tracing::info_span!(
+            "risten.route",
+            event = route.event_type(),
+            depth = route.depth(),
+            trace_id = Empty,
+            span_id = Empty,
+            parent_span_id = Empty,
+            outcome = Empty,
+            elapsed_us = Empty,
+        );
+        if let Some(trace) = route.trace_context() {
+            span.record("trace_id", format!("{:032x}", trace.trace_id()));
+            span.record("span_id", format!("{:016x}", trace.span_id()));
+        }
+        if let Some(parent) = route.parent_span_id() {
+            span.record("parent_span_id", format!("{parent:016x}"));
+        }
+        let future = context::with_context(Some(route), future);
+        let output = future.instrument(span.clone()).await;
+        record(&span, &output, start);
+        output
+    };
+    #[cfg(not(feature = "tracing"))]
+    let output = context::with_context(Some(route), future).await;
+
+    #[cfg(feature = "metrics")]
+    crate::metrics::record_route(
+        std::any::type_name::<E>(),
+        output.outcome(),
+        start.elapsed(),
+    );
+    output
+}
+
+/// Run `future` as the hook `name` of the current route.
+pub(crate) async fn hook<F>(
+    name: Option<Cow<'static, str>>,
+    priority: Option<i32>,
+    future: F,
+) -> F::Output
+where
+    F: Future,
+    F::Output: Outcome,
+{
+    #[cfg(any(feature = "tracing", feature = "metrics"))]
+    let start = std::time::Instant::now();
+    #[cfg(feature = "metrics")]
+    let metrics = context::current().map(|context| (context.event_type(), name.clone()));
+    #[cfg(feature = "tracing")]
+    let span = tracing::debug_span!(
+        "risten.hook",
+        event = context::current().map(|context| context.event_type()),
+        hook = name.as_deref(),
+        priority,
+        outcome = tracing::field::Empty,
+        elapsed_us = tracing::field::Empty,
+    );
+    #[cfg(not(feature = "tracing"))]
+    let _ = priority;
+    let future = context::in_hook(name, future);
+
+    #[cfg(feature = "tracing")]
+    let output = {
+        use tracing::Instrument;
+
+        let output = future.instrument(span.clone()).await;
+        record(&span, &output, start);
+        output
+    };
+    #[cfg(not(feature = "tracing"))]
+    let output = future.await;
+
+    #[cfg(feature = "metrics")]
+    if let Some((event, name)) = metrics {
+        crate::metrics::record_hook(event, name.as_deref(), output.outcome(), start.elapsed());
+    }
+    output
+}
+
+#[cfg(feature = "tracing")]
+fn record(span: &tracing::Span, output: &impl Outcome, start: std::time::Instant) {
+    span.record("outcome", output.outcome());
+    span.record("elapsed_us", start.elapsed().as_micros() as u64);
+}
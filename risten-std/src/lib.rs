@@ -12,8 +12,10 @@
 //!
 //! ## Helpers
 //!
-//! - **Standard hooks**: Logging, Timeout
+//! - **Standard hooks**: Logging, Timeout, Retry, Concurrency limit, Instrument, Propagation
 //! - **Standard listeners**: Filter, Map
+//! - **Metrics**: [`metrics::MetricsRecorder`] receives router and hook metrics
+//! - **Dispatch context**: [`context::current`] describes the event being routed
 //! - **Macros**: [`static_hooks!`], [`static_fanout!`]
 //!
 //! # Quick Start
@@ -39,11 +41,18 @@
 pub use risten_core;
 
 // Modules
+pub mod context;
 pub mod dynamic;
 pub mod hooks;
+mod instrument;
 pub mod listeners;
+#[cfg(feature = "metrics")]
+pub mod metrics;
 pub mod routing;
+#[cfg(feature = "runtime")]
+pub mod runtime;
 pub mod static_dispatch;
+mod task_local;
 pub mod testing;
 
 #[cfg(feature = "inventory")]
@@ -3,9 +3,11 @@
 //! This module provides common listener patterns:
 //! - **Filtering**: `FilterListener`, `AsyncFilterListener`
 //! - **Mapping**: `MapListener`, `AsyncMapListener`, `TryMapListener`
+//! - **Pass-through**: `PassThrough`
 
 pub mod filter;
 pub mod map;
+pub mod pass_through;
 
 pub use filter::{AsyncFilterListener, FilterListener};
 pub use map::{AsyncMapListener, MapListener, TryMapListener};
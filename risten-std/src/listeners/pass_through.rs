@@ -0,0 +1,54 @@
+//! Pass-through listener for starting pipelines.
+
+use risten_core::{BoxError, Listener, Message};
+use std::marker::PhantomData;
+
+/// A listener that passes every event through unchanged.
+///
+/// Useful as the start of a pipeline built from combinators, with the
+/// event type pinned so that closures further down can infer it.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let pipeline = PassThrough::<MyEvent>::new()
+///     .filter(|event| event.is_important())
+///     .handler(my_handler);
+/// ```
+pub struct PassThrough<E> {
+    _phantom: PhantomData<fn() -> E>,
+}
+
+impl<E> PassThrough<E> {
+    /// Create a new pass-through listener.
+    pub const fn new() -> Self {
+        Self {
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<E> Default for PassThrough<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> Clone for PassThrough<E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<E> Copy for PassThrough<E> {}
+
+impl<E> Listener<E> for PassThrough<E>
+where
+    E: Message + Clone + Sync,
+{
+    type Output = E;
+
+    async fn listen(&self, event: &E) -> Result<Option<Self::Output>, BoxError> {
+        Ok(Some(event.clone()))
+    }
+}
@@ -0,0 +1,310 @@
+//! Metrics of routes and hooks.
+//!
+//! Every router in this crate reports what it routes to a [`MetricsRecorder`]:
+//! dispatch, stop and error counters per event type, latency histograms and
+//! error and stop counters per hook, and gauges of the hooks running
+//! concurrently in a [`StaticFanoutRouter`](crate::static_dispatch::StaticFanoutRouter)
+//! and of the events queued behind a concurrency limit.
+//!
+//! Nothing is recorded until a recorder is installed, either for the whole
+//! process with [`set_recorder`] or for a single task with [`with_recorder`].
+//! [`InMemoryRecorder`] keeps the values for inspection in tests, and
+//! [`MetricsCrateRecorder`] forwards them to the [`metrics`](::metrics) facade.
+//!
+//! ```rust,ignore
+//! risten::metrics::set_recorder(MetricsCrateRecorder)?;
+//! ```
+
+use crate::task_local::{self, Scoped};
+use pin_project_lite::pin_project;
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex, OnceLock},
+    task::{Context, Poll},
+    time::Duration,
+};
+use thiserror::Error;
+
+/// Counter of events routed, labelled by `event`.
+pub const EVENTS_DISPATCHED: &str = "risten_events_dispatched_total";
+/// Counter of routes stopped by a hook, labelled by `event`.
+pub const EVENTS_STOPPED: &str = "risten_events_stopped_total";
+/// Counter of routes that failed, labelled by `event`.
+pub const EVENTS_FAILED: &str = "risten_events_failed_total";
+/// Histogram of route durations in seconds, labelled by `event`.
+pub const ROUTE_DURATION: &str = "risten_route_duration_seconds";
+/// Histogram of hook durations in seconds, labelled by `event` and `hook`.
+pub const HOOK_DURATION: &str = "risten_hook_duration_seconds";
+/// Counter of hooks that stopped propagation, labelled by `event` and `hook`.
+pub const HOOK_STOPS: &str = "risten_hook_stops_total";
+/// Counter of hooks that failed, labelled by `event` and `hook`.
+pub const HOOK_ERRORS: &str = "risten_hook_errors_total";
+/// Gauge of fan-out hooks running, labelled by `event`.
+pub const FANOUT_IN_FLIGHT: &str = "risten_fanout_in_flight";
+/// Gauge of events waiting for a concurrency limit, labelled by `event`.
+pub const QUEUE_DEPTH: &str = "risten_queue_depth";
+
+/// The `hook` label of hooks without a name.
+pub const ANONYMOUS_HOOK: &str = "anonymous";
+
+/// Metric labels, as key-value pairs.
+pub type Labels<'a> = &'a [(&'static str, &'a str)];
+
+/// A sink for the metrics reported by routers and hooks.
+pub trait MetricsRecorder: Send + Sync + 'static {
+    /// Add `value` to the counter `name`.
+    fn increment_counter(&self, name: &'static str, labels: Labels<'_>, value: u64);
+
+    /// Record `value` in the histogram `name`.
+    fn record_histogram(&self, name: &'static str, labels: Labels<'_>, value: f64);
+
+    /// Add `delta` to the gauge `name`.
+    fn adjust_gauge(&self, name: &'static str, labels: Labels<'_>, delta: f64);
+}
+
+impl<R: MetricsRecorder> MetricsRecorder for Arc<R> {
+    fn increment_counter(&self, name: &'static str, labels: Labels<'_>, value: u64) {
+        (**self).increment_counter(name, labels, value);
+    }
+
+    fn record_histogram(&self, name: &'static str, labels: Labels<'_>, value: f64) {
+        (**self).record_histogram(name, labels, value);
+    }
+
+    fn adjust_gauge(&self, name: &'static str, labels: Labels<'_>, delta: f64) {
+        (**self).adjust_gauge(name, labels, delta);
+    }
+}
+
+static GLOBAL: OnceLock<Arc<dyn MetricsRecorder>> = OnceLock::new();
+
+thread_local! {
+    static LOCAL: RefCell<Option<Arc<dyn MetricsRecorder>>> = const { RefCell::new(None) };
+}
+
+/// Returned by [`set_recorder`] when a recorder is already installed.
+#[derive(Debug, Error)]
+#[error("a metrics recorder is already installed")]
+pub struct SetRecorderError;
+
+/// Install the recorder of the whole process.
+///
+/// The recorder can only be installed once.
+pub fn set_recorder<R: MetricsRecorder>(recorder: R) -> Result<(), SetRecorderError> {
+    GLOBAL.set(Arc::new(recorder)).map_err(|_| SetRecorderError)
+}
+
+/// The recorder of the current task: the one set by [`with_recorder`], or the
+/// process recorder.
+pub fn recorder() -> Option<Arc<dyn MetricsRecorder>> {
+    task_local::current(&LOCAL).or_else(|| GLOBAL.get().cloned())
+}
+
+pin_project! {
+    /// A future reporting to its own recorder, created by [`with_recorder`].
+    pub struct WithRecorder<F> {
+        #[pin]
+        inner: Scoped<Arc<dyn MetricsRecorder>, F>,
+    }
+}
+
+impl<F: Future> Future for WithRecorder<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.project().inner.poll(cx)
+    }
+}
+
+/// Run `future` reporting to `recorder` instead of the process recorder.
+pub fn with_recorder<R, F>(recorder: R, future: F) -> WithRecorder<F>
+where
+    R: MetricsRecorder,
+    F: Future,
+{
+    WithRecorder {
+        inner: Scoped::new(&LOCAL, Some(Arc::new(recorder)), future),
+    }
+}
+
+/// Report a finished route of `event`.
+pub(crate) fn record_route(event: &'static str, outcome: &'static str, elapsed: Duration) {
+    let Some(recorder) = recorder() else {
+        return;
+    };
+    let labels = [("event", event)];
+    recorder.increment_counter(EVENTS_DISPATCHED, &labels, 1);
+    recorder.record_histogram(ROUTE_DURATION, &labels, elapsed.as_secs_f64());
+    match outcome {
+        "stop" => recorder.increment_counter(EVENTS_STOPPED, &labels, 1),
+        "error" => recorder.increment_counter(EVENTS_FAILED, &labels, 1),
+        _ => {}
+    }
+}
+
+/// Report a finished call of the hook `hook` for `event`.
+pub(crate) fn record_hook(
+    event: &'static str,
+    hook: Option<&str>,
+    outcome: &'static str,
+    elapsed: Duration,
+) {
+    let Some(recorder) = recorder() else {
+        return;
+    };
+    let labels = [("event", event), ("hook", hook.unwrap_or(ANONYMOUS_HOOK))];
+    recorder.record_histogram(HOOK_DURATION, &labels, elapsed.as_secs_f64());
+    match outcome {
+        "stop" => recorder.increment_counter(HOOK_STOPS, &labels, 1),
+        "error" => recorder.increment_counter(HOOK_ERRORS, &labels, 1),
+        _ => {}
+    }
+}
+
+/// Raises the gauge `name` of `event` by one until dropped.
+pub(crate) struct GaugeGuard {
+    recorder: Option<Arc<dyn MetricsRecorder>>,
+    name: &'static str,
+    event: &'static str,
+}
+
+impl GaugeGuard {
+    pub(crate) fn new(name: &'static str, event: &'static str) -> Self {
+        let recorder = recorder();
+        if let Some(recorder) = &recorder {
+            recorder.adjust_gauge(name, &[("event", event)], 1.0);
+        }
+        Self {
+            recorder,
+            name,
+            event,
+        }
+    }
+}
+
+impl Drop for GaugeGuard {
+    fn drop(&mut self) {
+        if let Some(recorder) = &self.recorder {
+            recorder.adjust_gauge(self.name, &[("event", self.event)], -1.0);
+        }
+    }
+}
+
+type Key = (&'static str, Vec<(&'static str, String)>);
+
+#[derive(Debug, Default)]
+struct Values {
+    counters: HashMap<Key, u64>,
+    histograms: HashMap<Key, Vec<f64>>,
+    gauges: HashMap<Key, f64>,
+}
+
+/// A recorder keeping every value in memory.
+///
+/// Clones share their values. Queries match the series of `name` carrying
+/// all of the given labels, so `&[]` aggregates over every series.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryRecorder {
+    values: Arc<Mutex<Values>>,
+}
+
+fn matches(key: &Key, name: &str, labels: Labels<'_>) -> bool {
+    key.0 == name
+        && labels
+            .iter()
+            .all(|&(name, value)| key.1.iter().any(|(n, v)| *n == name && v == value))
+}
+
+impl InMemoryRecorder {
+    /// Create an empty recorder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The sum of the matching counters.
+    pub fn counter(&self, name: &str, labels: Labels<'_>) -> u64 {
+        let values = self.values.lock().unwrap();
+        values
+            .counters
+            .iter()
+            .filter(|(key, _)| matches(key, name, labels))
+            .map(|(_, value)| value)
+            .sum()
+    }
+
+    /// The values recorded in the matching histograms.
+    pub fn histogram(&self, name: &str, labels: Labels<'_>) -> Vec<f64> {
+        let values = self.values.lock().unwrap();
+        values
+            .histograms
+            .iter()
+            .filter(|(key, _)| matches(key, name, labels))
+            .flat_map(|(_, values)| values.iter().copied())
+            .collect()
+    }
+
+    /// The sum of the matching gauges.
+    pub fn gauge(&self, name: &str, labels: Labels<'_>) -> f64 {
+        let values = self.values.lock().unwrap();
+        values
+            .gauges
+            .iter()
+            .filter(|(key, _)| matches(key, name, labels))
+            .map(|(_, value)| value)
+            .sum()
+    }
+
+    /// Forget every recorded value.
+    pub fn clear(&self) {
+        *self.values.lock().unwrap() = Values::default();
+    }
+}
+
+fn owned(labels: Labels<'_>) -> Vec<(&'static str, String)> {
+    labels
+        .iter()
+        .map(|&(name, value)| (name, value.to_owned()))
+        .collect()
+}
+
+impl MetricsRecorder for InMemoryRecorder {
+    fn increment_counter(&self, name: &'static str, labels: Labels<'_>, value: u64) {
+        let mut values = self.values.lock().unwrap();
+        *values.counters.entry((name, owned(labels))).or_default() += value;
+    }
+
+    fn record_histogram(&self, name: &'static str, labels: Labels<'_>, value: f64) {
+        let mut values = self.values.lock().unwrap();
+        values
+            .histograms
+            .entry((name, owned(labels)))
+            .or_default()
+            .push(value);
+    }
+
+    fn adjust_gauge(&self, name: &'static str, labels: Labels<'_>, delta: f64) {
+        let mut values = self.values.lock().unwrap();
+        *values.gauges.entry((name, owned(labels))).or_default() += delta;
+    }
+}
+
+/// A recorder forwarding to the recorder installed in the [`metrics`](::metrics) facade.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MetricsCrateRecorder;
+
+impl MetricsRecorder for MetricsCrateRecorder {
+    fn increment_counter(&self, name: &'static str, labels: Labels<'_>, value: u64) {
+        ::metrics::counter!(name, &owned(labels)).increment(value);
+    }
+
+    fn record_histogram(&self, name: &'static str, labels: Labels<'_>, value: f64) {
+        ::metrics::histogram!(name, &owned(labels)).record(value);
+    }
+
+    fn adjust_gauge(&self, name: &'static str, labels: Labels<'_>, delta: f64) {
+        ::metrics::gauge!(name, &owned(labels)).increment(delta);
+    }
+}
@@ -25,11 +25,16 @@
 //! router.route(&event).await?;
 //! ```
 
+use crate::instrument;
 use futures::future::join_all;
-use risten_core::{DynHandler, ExtractError, Message, RouteResult, Router};
+use risten_core::{DynHandler, ExtractError, Handler, HookResult, Message, RouteResult, Router};
 use std::any::{Any, TypeId};
+use std::borrow::Cow;
+use std::fmt;
 use std::future::Future;
 use std::pin::Pin;
+use std::sync::atomic::{AtomicU8, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, PoisonError, RwLock};
 use thiserror::Error;
 
 /// Type-erased handler trait for dynamic dispatch.
@@ -44,6 +49,11 @@ pub trait ErasedHandler: Send + Sync {
         &'a self,
         event: &'a (dyn Any + Send + Sync),
     ) -> Pin<Box<dyn Future<Output = Result<(), ExtractError>> + Send + 'a>>;
+
+    /// The name of the handler, for diagnostics.
+    fn name(&self) -> Cow<'static, str> {
+        Cow::Borrowed(std::any::type_name::<Self>())
+    }
 }
 
 /// Wrapper to implement [`ErasedHandler`] for a typed handler.
@@ -81,6 +91,124 @@ where
         let event_owned = event_ref.clone();
         self.handler.call_dyn(event_owned)
     }
+
+    fn name(&self) -> Cow<'static, str> {
+        self.handler.name_dyn()
+    }
+}
+
+/// Adapts a handler of an enum variant's payload into a handler of the enum.
+///
+/// Generated by `#[subscribe(Enum::Variant)]`. Events of other variants are
+/// skipped as a soft rejection, so they are not counted as executed.
+pub struct VariantHandler<E, P, H> {
+    handler: H,
+    project: fn(E) -> Option<P>,
+}
+
+impl<E, P, H> VariantHandler<E, P, H> {
+    /// Create a new variant handler from a payload handler and a projection.
+    pub const fn new(handler: H, project: fn(E) -> Option<P>) -> Self {
+        Self { handler, project }
+    }
+}
+
+impl<E, P, H> Handler<E> for VariantHandler<E, P, H>
+where
+    E: Message,
+    P: Message,
+    H: Handler<P, Output = Result<(), ExtractError>>,
+{
+    type Output = Result<(), ExtractError>;
+
+    async fn call(&self, event: E) -> Self::Output {
+        match (self.project)(event) {
+            Some(payload) => self.handler.call(payload).await,
+            None => Err(ExtractError::skipped("event is a different variant")),
+        }
+    }
+
+    fn name(&self) -> Cow<'static, str> {
+        self.handler.name()
+    }
+}
+
+/// Runs a handler until its first successful call, then skips every event.
+///
+/// Generated by `#[subscribe(once)]`. Failed calls (including rejections)
+/// leave the handler enabled.
+///
+/// Only one call runs at a time: events arriving while a call is in flight,
+/// e.g. from the parallel [`DispatchRouter`], are skipped rather than queued,
+/// even if that call then fails.
+pub struct OnceHandler<H> {
+    handler: H,
+    state: AtomicU8,
+}
+
+// States of a `OnceHandler`
+const IDLE: u8 = 0;
+const RUNNING: u8 = 1;
+const DONE: u8 = 2;
+
+impl<H> OnceHandler<H> {
+    /// Create a new handler that runs successfully at most once.
+    pub const fn new(handler: H) -> Self {
+        Self {
+            handler,
+            state: AtomicU8::new(IDLE),
+        }
+    }
+
+    /// Whether the handler has already completed its run.
+    pub fn is_done(&self) -> bool {
+        self.state.load(Ordering::Acquire) == DONE
+    }
+}
+
+impl<E, H> Handler<E> for OnceHandler<H>
+where
+    E: Message,
+    H: Handler<E, Output = Result<(), ExtractError>>,
+{
+    type Output = Result<(), ExtractError>;
+
+    async fn call(&self, event: E) -> Self::Output {
+        // Claim the run up front so concurrent events cannot both run it
+        match self
+            .state
+            .compare_exchange(IDLE, RUNNING, Ordering::AcqRel, Ordering::Acquire)
+        {
+            Ok(_) => {}
+            Err(RUNNING) => return Err(ExtractError::skipped("handler is already running")),
+            Err(_) => return Err(ExtractError::skipped("handler already ran once")),
+        }
+        let result = self.handler.call(event).await;
+        let next = if result.is_ok() { DONE } else { IDLE };
+        self.state.store(next, Ordering::Release);
+        result
+    }
+
+    fn name(&self) -> Cow<'static, str> {
+        self.handler.name()
+    }
+}
+
+/// Where a handler is declared in the source, as recorded by `#[subscribe]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceLocation {
+    /// The module path of the handler.
+    pub module_path: &'static str,
+    /// The source file of the handler.
+    pub file: &'static str,
+    /// The line of the handler in `file`.
+    pub line: u32,
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({}:{})", self.module_path, self.file, self.line)
+    }
 }
 
 /// Registration entry for a handler in the global registry.
@@ -93,10 +221,235 @@ pub struct HandlerRegistration {
     pub handler: &'static (dyn ErasedHandler + Send + Sync),
     /// Priority for execution ordering (higher = earlier).
     pub priority: i32,
+    /// The enum variant this handler subscribes to, if any.
+    pub variant: Option<&'static str>,
+    /// The handler's name, for introspection.
+    pub name: Option<&'static str>,
+    /// Free-form tags, for introspection.
+    pub tags: &'static [&'static str],
+    /// Where the handler is declared, if known.
+    pub location: Option<SourceLocation>,
+}
+
+impl HandlerRegistration {
+    /// Create a registration for every event of the given type.
+    pub const fn new(
+        type_id: TypeId,
+        handler: &'static (dyn ErasedHandler + Send + Sync),
+        priority: i32,
+    ) -> Self {
+        Self {
+            type_id,
+            handler,
+            priority,
+            variant: None,
+            name: None,
+            tags: &[],
+            location: None,
+        }
+    }
+
+    /// Restrict this registration to one enum variant.
+    pub const fn with_variant(mut self, variant: &'static str) -> Self {
+        self.variant = Some(variant);
+        self
+    }
+
+    /// Set the handler's name.
+    pub const fn with_name(mut self, name: &'static str) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Set the handler's tags.
+    pub const fn with_tags(mut self, tags: &'static [&'static str]) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// Set where the handler is declared.
+    pub const fn with_location(mut self, location: SourceLocation) -> Self {
+        self.location = Some(location);
+        self
+    }
+
+    /// The handler's name, or the [name](ErasedHandler::name) of the
+    /// handler itself if the registration has none.
+    pub fn handler_name(&self) -> Cow<'static, str> {
+        match self.name {
+            Some(name) => Cow::Borrowed(name),
+            None => self.handler.name(),
+        }
+    }
+
+    /// Whether the handler is tagged with `tag`.
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.contains(&tag)
+    }
+
+    /// Whether this registration should receive `event`.
+    ///
+    /// Variant subscriptions are matched against [`Message::variant`]; events
+    /// that don't report a variant are offered to every handler of their type.
+    pub fn handles<E: Message>(&self, event: &E) -> bool {
+        if self.type_id != TypeId::of::<E>() {
+            return false;
+        }
+        match (self.variant, event.variant()) {
+            (Some(expected), Some(actual)) => expected == actual,
+            _ => true,
+        }
+    }
 }
 
 inventory::collect!(HandlerRegistration);
 
+/// A handler registered at runtime, e.g. by a [`Subscriber`].
+struct RuntimeRegistration {
+    id: u64,
+    type_id: TypeId,
+    handler: Box<dyn ErasedHandler + Send + Sync>,
+    priority: i32,
+}
+
+/// Handlers registered at runtime, alongside the `inventory` registrations.
+static RUNTIME_HANDLERS: RwLock<Vec<Arc<RuntimeRegistration>>> = RwLock::new(Vec::new());
+
+static NEXT_RUNTIME_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Length of [`RUNTIME_HANDLERS`], so dispatch can skip the lock while no
+/// handler is registered at runtime.
+static RUNTIME_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// A handler selected for an event, from either registry.
+enum ActiveHandler {
+    Static(&'static HandlerRegistration),
+    Runtime(Arc<RuntimeRegistration>),
+}
+
+impl ActiveHandler {
+    fn priority(&self) -> i32 {
+        match self {
+            Self::Static(reg) => reg.priority,
+            Self::Runtime(reg) => reg.priority,
+        }
+    }
+
+    fn handler(&self) -> &(dyn ErasedHandler + Send + Sync) {
+        match self {
+            Self::Static(reg) => reg.handler,
+            Self::Runtime(reg) => &*reg.handler,
+        }
+    }
+
+    fn name(&self) -> Cow<'static, str> {
+        match self {
+            Self::Static(reg) => reg.handler_name(),
+            Self::Runtime(reg) => reg.handler.name(),
+        }
+    }
+
+    /// Calls the handler within the dispatch context of the current route.
+    ///
+    /// Errors are tagged with the handler's name.
+    async fn call(&self, event: &(dyn Any + Send + Sync)) -> Result<(), ExtractError> {
+        let name = self.name();
+        instrument::hook(
+            Some(name.clone()),
+            Some(self.priority()),
+            self.handler().call_erased(event),
+        )
+        .await
+        .map_err(|err| err.with_handler(name))
+    }
+}
+
+/// Collects the handlers for `event` from both registries.
+///
+/// Runtime registrations are snapshotted so that handlers may (un)register
+/// while the event is dispatched.
+fn active_handlers<E: Message + 'static>(event: &E) -> Vec<ActiveHandler> {
+    let target_type = TypeId::of::<E>();
+    let mut handlers: Vec<_> = inventory::iter::<HandlerRegistration>()
+        .filter(|reg| reg.handles(event))
+        .map(ActiveHandler::Static)
+        .collect();
+    if RUNTIME_COUNT.load(Ordering::Acquire) == 0 {
+        return handlers;
+    }
+    let runtime = RUNTIME_HANDLERS
+        .read()
+        .unwrap_or_else(PoisonError::into_inner);
+    handlers.extend(
+        runtime
+            .iter()
+            .filter(|reg| reg.type_id == target_type)
+            .cloned()
+            .map(ActiveHandler::Runtime),
+    );
+    handlers
+}
+
+/// Handle to handlers registered at runtime.
+///
+/// Dropping the handle keeps the handlers registered; call
+/// [`unregister`](Self::unregister) to remove them.
+#[derive(Debug, Default)]
+#[must_use = "dropping a Subscription keeps its handlers registered forever"]
+pub struct Subscription {
+    ids: Vec<u64>,
+}
+
+impl Subscription {
+    /// Create an empty subscription.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the handlers of `other` to this subscription.
+    pub fn merge(&mut self, other: Subscription) {
+        self.ids.extend(other.ids);
+    }
+
+    /// Get the number of handlers in this subscription.
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Check if the subscription holds no handlers.
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Unregister every handler of this subscription.
+    ///
+    /// Events that are already being dispatched may still reach them.
+    pub fn unregister(self) {
+        let mut runtime = RUNTIME_HANDLERS
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        runtime.retain(|reg| !self.ids.contains(&reg.id));
+        RUNTIME_COUNT.store(runtime.len(), Ordering::Release);
+    }
+}
+
+/// A type whose methods subscribe to events on a shared instance.
+///
+/// Implemented by `#[risten::subscriber]` on an `impl` block: every `#[on]`
+/// method becomes a handler holding an `Arc` of the instance.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let subscription = Arc::new(MyService::new()).subscribe();
+/// // ...
+/// subscription.unregister();
+/// ```
+pub trait Subscriber: Send + Sync + 'static {
+    /// Register a handler for every subscribed method of this instance.
+    fn subscribe(self: Arc<Self>) -> Subscription;
+}
+
 /// Errors that can occur during dispatch routing.
 #[derive(Debug, Error)]
 pub enum DispatchError {
@@ -107,6 +460,48 @@ pub enum DispatchError {
     /// A generic error from handler execution.
     #[error(transparent)]
     Other(#[from] Box<dyn std::error::Error + Send + Sync>),
+
+    /// No handlers are registered for the event type.
+    #[error("no handlers registered for `{0}`")]
+    NoHandlers(&'static str),
+
+    /// Two handlers of the same event type share a name.
+    #[error("handler name `{name}` is registered more than once for `{event}`")]
+    DuplicateName {
+        /// The duplicated handler name.
+        name: &'static str,
+        /// The event type the handlers are registered for.
+        event: &'static str,
+    },
+}
+
+/// Startup checks for a router, run by `#[risten::main(router = ...)]`.
+pub trait ValidateRouter {
+    /// Check that the router is ready to dispatch events.
+    fn validate(&self) -> Result<(), DispatchError>;
+}
+
+/// Checks that event type `E` has handlers and that their names are unique.
+///
+/// Handlers registered at runtime count as handlers. Only explicit names are
+/// compared, so several instances of a `#[subscriber]` type can subscribe
+/// under the same default name.
+fn validate_registrations<E: 'static>() -> Result<(), DispatchError> {
+    let event = std::any::type_name::<E>();
+    if DispatchRouter::<E>::handler_count() == 0 {
+        return Err(DispatchError::NoHandlers(event));
+    }
+    let mut names: Vec<_> = DispatchRouter::<E>::registrations()
+        .filter_map(|reg| reg.name)
+        .collect();
+    names.sort_unstable();
+    match names.windows(2).find(|pair| pair[0] == pair[1]) {
+        Some(pair) => Err(DispatchError::DuplicateName {
+            name: pair[0],
+            event,
+        }),
+        None => Ok(()),
+    }
 }
 
 /// A router that collects and executes handlers registered via `inventory`.
@@ -142,15 +537,58 @@ impl<E> DispatchRouter<E> {
         }
     }
 
-    /// Get the number of handlers registered for event type `E`.
+    /// Get the number of handlers registered for event type `E`, including
+    /// the ones registered at runtime.
     pub fn handler_count() -> usize
     where
         E: 'static,
     {
         let target_type = TypeId::of::<E>();
-        inventory::iter::<HandlerRegistration>()
+        let runtime = RUNTIME_HANDLERS
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
             .filter(|reg| reg.type_id == target_type)
-            .count()
+            .count();
+        Self::registrations().count() + runtime
+    }
+
+    /// Iterate over the registrations for event type `E`, e.g. to inspect
+    /// their names and tags.
+    pub fn registrations() -> impl Iterator<Item = &'static HandlerRegistration>
+    where
+        E: 'static,
+    {
+        let target_type = TypeId::of::<E>();
+        inventory::iter::<HandlerRegistration>().filter(move |reg| reg.type_id == target_type)
+    }
+}
+
+impl<E> DispatchRouter<E>
+where
+    E: Message + Clone + 'static,
+{
+    /// Register a handler for event type `E` at runtime.
+    ///
+    /// The handler runs alongside the `#[subscribe]` handlers until the
+    /// returned [`Subscription`] is unregistered.
+    pub fn register<H>(handler: H, priority: i32) -> Subscription
+    where
+        H: Handler<E, Output = Result<(), ExtractError>> + Send + Sync + 'static,
+    {
+        let id = NEXT_RUNTIME_ID.fetch_add(1, Ordering::Relaxed);
+        let registration = RuntimeRegistration {
+            id,
+            type_id: TypeId::of::<E>(),
+            handler: Box::new(ErasedHandlerWrapper::<E, H>::new(handler)),
+            priority,
+        };
+        let mut runtime = RUNTIME_HANDLERS
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        runtime.push(Arc::new(registration));
+        RUNTIME_COUNT.store(runtime.len(), Ordering::Release);
+        Subscription { ids: vec![id] }
     }
 }
 
@@ -160,6 +598,12 @@ impl<E> Default for DispatchRouter<E> {
     }
 }
 
+impl<E: 'static> ValidateRouter for DispatchRouter<E> {
+    fn validate(&self) -> Result<(), DispatchError> {
+        validate_registrations::<E>()
+    }
+}
+
 impl<E> Router<E> for DispatchRouter<E>
 where
     E: Message + Clone + 'static,
@@ -167,36 +611,43 @@ where
     type Error = DispatchError;
 
     async fn route(&self, event: &E) -> Result<RouteResult, Self::Error> {
-        let target_type = TypeId::of::<E>();
+        instrument::route(event, self.dispatch(event)).await
+    }
+}
+
+impl<E> DispatchRouter<E>
+where
+    E: Message + Clone + 'static,
+{
+    async fn dispatch(&self, event: &E) -> Result<RouteResult, DispatchError> {
         let any_event = event as &(dyn Any + Send + Sync);
 
-        // Collect all handlers for this event type
-        let handlers: Vec<_> = inventory::iter::<HandlerRegistration>()
-            .filter(|reg| reg.type_id == target_type)
-            .collect();
+        // Collect all handlers for this event (and variant)
+        let handlers = active_handlers(event);
 
         if handlers.is_empty() {
             return Ok(RouteResult::continued());
         }
 
-        let handler_count = handlers.len();
-
         // Execute all handlers in parallel
-        let futures: Vec<_> = handlers
-            .iter()
-            .map(|reg| reg.handler.call_erased(any_event))
-            .collect();
+        let futures: Vec<_> = handlers.iter().map(|reg| reg.call(any_event)).collect();
 
         let results = join_all(futures).await;
 
-        // Check for errors
+        // Check for errors; soft extractor rejections skip the handler, and
+        // stop the route if they resolve to `Stop`
+        let mut result = RouteResult::continued();
         for res in results {
-            if let Err(e) = res {
-                return Err(DispatchError::Extract(e));
+            match res {
+                Ok(()) => result.executed_count += 1,
+                Err(e) if e.is_rejection() => {
+                    result.stopped |= e.outcome() == Some(HookResult::Stop);
+                }
+                Err(e) => return Err(DispatchError::Extract(e)),
             }
         }
 
-        Ok(RouteResult::with_count(handler_count))
+        Ok(result)
     }
 }
 
@@ -230,6 +681,12 @@ impl<E> Default for SequentialDispatchRouter<E> {
     }
 }
 
+impl<E: 'static> ValidateRouter for SequentialDispatchRouter<E> {
+    fn validate(&self) -> Result<(), DispatchError> {
+        validate_registrations::<E>()
+    }
+}
+
 impl<E> Router<E> for SequentialDispatchRouter<E>
 where
     E: Message + Clone + 'static,
@@ -237,30 +694,44 @@ where
     type Error = DispatchError;
 
     async fn route(&self, event: &E) -> Result<RouteResult, Self::Error> {
-        let target_type = TypeId::of::<E>();
+        instrument::route(event, self.dispatch(event)).await
+    }
+}
+
+impl<E> SequentialDispatchRouter<E>
+where
+    E: Message + Clone + 'static,
+{
+    async fn dispatch(&self, event: &E) -> Result<RouteResult, DispatchError> {
         let any_event = event as &(dyn Any + Send + Sync);
 
-        // Collect all handlers for this event type, sorted by priority
-        let mut handlers: Vec<_> = inventory::iter::<HandlerRegistration>()
-            .filter(|reg| reg.type_id == target_type)
-            .collect();
+        // Collect all handlers for this event (and variant), sorted by priority
+        let mut handlers = active_handlers(event);
 
         // Sort by priority (higher priority = earlier execution)
-        handlers.sort_by(|a, b| b.priority.cmp(&a.priority));
+        handlers.sort_by_key(|reg| std::cmp::Reverse(reg.priority()));
 
         if handlers.is_empty() {
             return Ok(RouteResult::continued());
         }
 
-        let mut executed_count = 0;
+        let mut result = RouteResult::continued();
 
-        // Execute handlers sequentially
+        // Execute handlers sequentially; soft extractor rejections skip the
+        // handler, or skip the remaining handlers if they resolve to `Stop`
         for reg in handlers {
-            reg.handler.call_erased(any_event).await?;
-            executed_count += 1;
+            match reg.call(any_event).await {
+                Ok(()) => result.executed_count += 1,
+                Err(e) if e.outcome() == Some(HookResult::Stop) => {
+                    result.stopped = true;
+                    break;
+                }
+                Err(e) if e.is_rejection() => {}
+                Err(e) => return Err(DispatchError::Extract(e)),
+            }
         }
 
-        Ok(RouteResult::with_count(executed_count))
+        Ok(result)
     }
 }
 
@@ -329,6 +800,12 @@ impl<E> Default for ConfigurableDispatchRouter<E> {
     }
 }
 
+impl<E: 'static> ValidateRouter for ConfigurableDispatchRouter<E> {
+    fn validate(&self) -> Result<(), DispatchError> {
+        validate_registrations::<E>()
+    }
+}
+
 impl<E> Router<E> for ConfigurableDispatchRouter<E>
 where
     E: Message + Clone + 'static,
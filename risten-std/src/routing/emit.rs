@@ -0,0 +1,235 @@
+//! Follow-up event emission from handlers.
+//!
+//! An [`Emitter`] is an extractor that lets a handler publish new events
+//! while it processes one. Emitted events are dispatched through
+//! [`DispatchRouter`] (or any router passed to [`Emitter::emit_to`]) and
+//! carry their [`Causation`]: the chain of event types that led to them.
+//!
+//! Each cascade is bounded: it fails with [`EmitError::DepthExceeded`] once
+//! it grows past the emitter's maximum depth, and with [`EmitError::Loop`] as
+//! soon as an event type would be emitted twice in the same chain, so a
+//! handler re-emitting its own event type cannot recurse forever.
+//!
+//! An [`Envelope`](risten_core::Envelope) emitted while handling another envelope joins its flow:
+//! it inherits the correlation id and records the handled event as its cause.
+//! It also continues the trace of the handler: the emitter captures the trace
+//! context of the current route when it is extracted, and stores it in the
+//! `traceparent` header of emitted envelopes that have none.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! #[subscribe]
+//! async fn on_order(order: OrderPlaced, emitter: Emitter) -> Result<(), EmitError> {
+//!     emitter.emit(SendReceipt { order_id: order.id }).await?;
+//!     Ok(())
+//! }
+//! ```
+
+use super::dispatch::DispatchRouter;
+use crate::context;
+use crate::task_local::{self, Scoped};
+use risten_core::{
+    BoxError, FromEvent, Message, Metadata, RouteResult, Router, TRACEPARENT, TraceContext,
+};
+use std::{cell::RefCell, convert::Infallible, sync::Arc};
+use thiserror::Error;
+
+/// Maximum cascade depth of an emitter unless configured otherwise.
+pub const DEFAULT_MAX_DEPTH: usize = 8;
+
+thread_local! {
+    /// The causation chain of the cascade being polled on this thread.
+    static CURRENT: RefCell<Option<Arc<[&'static str]>>> = const { RefCell::new(None) };
+}
+
+/// Errors that can occur when emitting a follow-up event.
+#[derive(Debug, Error)]
+pub enum EmitError {
+    /// The cascade grew past the emitter's maximum depth.
+    #[error("event cascade exceeded the maximum depth of {max}: {chain:?}")]
+    DepthExceeded {
+        /// The maximum depth of the emitter.
+        max: usize,
+        /// The causation chain of the rejected event, root first.
+        chain: Vec<&'static str>,
+    },
+
+    /// The event type already appears in the causation chain.
+    #[error("event `{event}` would loop through its own causation chain: {chain:?}")]
+    Loop {
+        /// The type of the rejected event.
+        event: &'static str,
+        /// The causation chain of the rejected event, root first.
+        chain: Vec<&'static str>,
+    },
+
+    /// Routing the emitted event failed.
+    #[error("failed to route emitted event")]
+    Routing(#[source] BoxError),
+}
+
+/// The chain of event types that caused an event, root first.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Causation {
+    chain: Arc<[&'static str]>,
+}
+
+impl Causation {
+    /// The causation of the cascade currently being handled on this task.
+    ///
+    /// Returns `None` outside of events emitted through an [`Emitter`].
+    pub fn current() -> Option<Self> {
+        task_local::current(&CURRENT).map(|chain| Causation { chain })
+    }
+
+    /// The event types that led to the current event, root first.
+    pub fn chain(&self) -> &[&'static str] {
+        &self.chain
+    }
+
+    /// The number of emissions between the root event and the current event.
+    pub fn depth(&self) -> usize {
+        self.chain.len()
+    }
+
+    /// The type of the event that started the cascade.
+    pub fn root(&self) -> Option<&'static str> {
+        self.chain.first().copied()
+    }
+}
+
+/// An extractor emitting follow-up events from a handler.
+///
+/// Extracted for any event type; it records that event as the cause of
+/// everything it emits. Use [`Emitter::new`] to start a cascade outside of a
+/// handler.
+#[derive(Debug, Clone)]
+pub struct Emitter {
+    causation: Causation,
+    parent: Option<Metadata>,
+    trace: Option<TraceContext>,
+    max_depth: usize,
+}
+
+impl Default for Emitter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Emitter {
+    /// Create an emitter starting a new cascade.
+    pub fn new() -> Self {
+        Self {
+            causation: Causation::default(),
+            parent: None,
+            trace: current_trace(),
+            max_depth: DEFAULT_MAX_DEPTH,
+        }
+    }
+
+    /// Create an emitter for a handler of `event`, inside the current cascade.
+    pub fn caused_by<E: Message>(event: &E) -> Self {
+        let parent = Causation::current().unwrap_or_default();
+        let mut chain = parent.chain.to_vec();
+        chain.push(std::any::type_name::<E>());
+        Self {
+            causation: Causation {
+                chain: chain.into(),
+            },
+            parent: event.metadata().cloned(),
+            trace: current_trace(),
+            max_depth: DEFAULT_MAX_DEPTH,
+        }
+    }
+
+    /// Set the maximum cascade depth of events emitted by this emitter.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// The causation recorded on emitted events.
+    pub fn causation(&self) -> &Causation {
+        &self.causation
+    }
+
+    /// The metadata of the handled event, if it is an [`Envelope`](risten_core::Envelope).
+    pub fn parent(&self) -> Option<&Metadata> {
+        self.parent.as_ref()
+    }
+
+    /// The trace context recorded on emitted envelopes, if the handled event is traced.
+    pub fn trace_context(&self) -> Option<&TraceContext> {
+        self.trace.as_ref()
+    }
+
+    /// Emit `event` to the handlers registered with [`DispatchRouter`].
+    pub async fn emit<T>(&self, event: T) -> Result<RouteResult, EmitError>
+    where
+        T: Message + Clone,
+    {
+        self.emit_to(&DispatchRouter::<T>::new(), event).await
+    }
+
+    /// Emit `event` to `router`.
+    pub async fn emit_to<T, R>(&self, router: &R, mut event: T) -> Result<RouteResult, EmitError>
+    where
+        T: Message,
+        R: Router<T>,
+    {
+        let chain = self.check::<T>()?;
+        if let Some(parent) = &self.parent
+            && let Some(metadata) = event.metadata_mut()
+            && !metadata.has_cause()
+        {
+            metadata.set_cause(parent);
+        }
+        if let Some(trace) = &self.trace
+            && let Some(metadata) = event.metadata_mut()
+            && metadata.header(TRACEPARENT).is_none()
+        {
+            metadata.set_trace_context(trace);
+        }
+        Scoped::new(&CURRENT, Some(chain), router.route(&event))
+            .await
+            .map_err(|err| EmitError::Routing(Box::new(err)))
+    }
+
+    /// Checks the cascade limits for an event of type `T`.
+    fn check<T: 'static>(&self) -> Result<Arc<[&'static str]>, EmitError> {
+        let event = std::any::type_name::<T>();
+        let chain = self.causation.chain();
+        if chain.contains(&event) {
+            return Err(EmitError::Loop {
+                event,
+                chain: chain.to_vec(),
+            });
+        }
+        if chain.len() >= self.max_depth {
+            return Err(EmitError::DepthExceeded {
+                max: self.max_depth,
+                chain: chain.to_vec(),
+            });
+        }
+        Ok(Arc::clone(&self.causation.chain))
+    }
+}
+
+/// The trace context of the span running on this task.
+fn current_trace() -> Option<TraceContext> {
+    #[cfg(feature = "opentelemetry")]
+    if let Some(trace) = crate::hooks::propagation::current_trace_context() {
+        return Some(trace);
+    }
+    context::current().and_then(|context| context.trace_context().cloned())
+}
+
+impl<E: Message> FromEvent<E> for Emitter {
+    type Error = Infallible;
+
+    fn from_event(event: &E) -> Result<Self, Self::Error> {
+        Ok(Emitter::caused_by(event))
+    }
+}
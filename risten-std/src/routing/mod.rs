@@ -4,6 +4,7 @@
 //!
 //! - **Static routing**: Compile-time fixed hook chains via HList.
 //! - **Dispatch routing**: Inventory-based automatic handler collection.
+//! - **Nested routing**: Sub-routers mounted under a key.
 //!
 //! # Choosing a Router
 //!
@@ -11,12 +12,17 @@
 //! |--------|----------|-------------|
 //! | `StaticRouter` | Known handlers at compile time | Zero-cost, fully inlined |
 //! | `DispatchRouter` | Dynamic handler discovery | Small runtime overhead |
+//! | `NestedRouter` | Composing feature routers by key | One map lookup per event |
 
 #[cfg(feature = "inventory")]
 pub mod dispatch;
+#[cfg(feature = "inventory")]
+pub mod emit;
+pub mod nest;
 
 #[cfg(feature = "inventory")]
 pub use dispatch::{
     ConfigurableDispatchRouter, DispatchError, DispatchMode, DispatchRouter, ErasedHandler,
-    ErasedHandlerWrapper, HandlerRegistration, SequentialDispatchRouter,
+    ErasedHandlerWrapper, HandlerRegistration, OnceHandler, SequentialDispatchRouter, Subscriber,
+    Subscription, ValidateRouter, VariantHandler,
 };
@@ -0,0 +1,135 @@
+//! Nested routing with mount points.
+//!
+//! A [`NestedRouter`] extracts a key from each event and forwards the event
+//! to the sub-router mounted under that key. Feature modules can own their
+//! own `StaticRouter`, `DynamicRouter` or nested router and be composed at
+//! the application root.
+
+use crate::context;
+use risten_core::{BoxRouter, Message, RistenError, RouteResult, Router, RouterExt};
+use std::{collections::HashMap, fmt::Debug, hash::Hash};
+
+/// Extracts the mount key of an event.
+type KeyFn<E, K> = Box<dyn Fn(&E) -> Option<K> + Send + Sync>;
+
+/// A router forwarding events to sub-routers mounted under a key.
+///
+/// The key is extracted from each event (a topic segment, a guild id, an
+/// enum variant, ...). Events whose key has no mount go to the
+/// [`otherwise`](Self::otherwise) router if one is set, and are otherwise
+/// left unhandled.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// // Keyed by the first segment of the command path, e.g. "music/play"
+/// let root = NestedRouter::new(|event: &Command| event.segment(0))
+///     .mount("admin".to_string(), admin::router())
+///     // Strip the mount segment before forwarding
+///     .mount_with("music".to_string(), Command::strip_segment, music::router())
+///     .otherwise(unknown_command_router);
+///
+/// root.route(&command).await?;
+/// ```
+pub struct NestedRouter<E: Message, K> {
+    key: KeyFn<E, K>,
+    mounts: HashMap<K, BoxRouter<E>>,
+    otherwise: Option<BoxRouter<E>>,
+}
+
+impl<E, K> NestedRouter<E, K>
+where
+    E: Message,
+    K: Eq + Hash + Debug + Send + Sync + 'static,
+{
+    /// Create a router keyed by `key`.
+    ///
+    /// Events for which `key` returns `None` are never forwarded to a mount.
+    pub fn new<F>(key: F) -> Self
+    where
+        F: Fn(&E) -> Option<K> + Send + Sync + 'static,
+    {
+        Self {
+            key: Box::new(key),
+            mounts: HashMap::new(),
+            otherwise: None,
+        }
+    }
+
+    /// Mount `router` under `key`, forwarding matching events unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a router is already mounted under `key`.
+    pub fn mount<R>(mut self, key: K, router: R) -> Self
+    where
+        R: Router<E> + 'static,
+    {
+        if self.mounts.contains_key(&key) {
+            panic!("a router is already mounted under {key:?}");
+        }
+        self.mounts.insert(key, router.boxed());
+        self
+    }
+
+    /// Mount `router` under `key`, rewriting matching events before forwarding.
+    ///
+    /// `rewrite` can strip the key from the event or convert it to the
+    /// sub-router's own event type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a router is already mounted under `key`.
+    pub fn mount_with<F, Inner, R>(self, key: K, rewrite: F, router: R) -> Self
+    where
+        Inner: Message,
+        F: Fn(&E) -> Inner + Send + Sync + 'static,
+        R: Router<Inner> + 'static,
+    {
+        self.mount(key, router.map_event(rewrite))
+    }
+
+    /// Route events whose key has no mount to `router`.
+    pub fn otherwise<R>(mut self, router: R) -> Self
+    where
+        R: Router<E> + 'static,
+    {
+        self.otherwise = Some(router.boxed());
+        self
+    }
+
+    /// Whether a router is mounted under `key`.
+    pub fn is_mounted(&self, key: &K) -> bool {
+        self.mounts.contains_key(key)
+    }
+
+    /// The keys of all mounted routers, in no particular order.
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.mounts.keys()
+    }
+}
+
+impl<E: Message> NestedRouter<E, &'static str> {
+    /// Create a router keyed by the enum variant of the event.
+    ///
+    /// See [`Message::variant`]; mount sub-routers under variant names.
+    pub fn by_variant() -> Self {
+        Self::new(|event: &E| event.variant())
+    }
+}
+
+impl<E, K> Router<E> for NestedRouter<E, K>
+where
+    E: Message,
+    K: Eq + Hash + Send + Sync + 'static,
+{
+    type Error = RistenError;
+
+    async fn route(&self, event: &E) -> Result<RouteResult, Self::Error> {
+        let mounted = (self.key)(event).and_then(|key| self.mounts.get(&key));
+        match mounted.or(self.otherwise.as_ref()) {
+            Some(router) => context::in_route(event, router.route(event)).await,
+            None => Ok(RouteResult::continued()),
+        }
+    }
+}
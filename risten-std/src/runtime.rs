@@ -0,0 +1,107 @@
+//! Runtime lifecycle support for `#[risten::main]`.
+//!
+//! Provides a process-wide shutdown signal, triggered by Ctrl-C, `SIGTERM`
+//! or [`request_shutdown`], and [`run_until_shutdown`] to run an
+//! application future with a grace period once shutdown is requested.
+
+use std::future::Future;
+use std::pin::pin;
+use std::process::ExitCode;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tokio::sync::Notify;
+
+static SHUTDOWN_REQUESTED: AtomicBool = AtomicBool::new(false);
+static SHUTDOWN: Notify = Notify::const_new();
+
+/// Request a graceful shutdown, as if Ctrl-C had been pressed.
+pub fn request_shutdown() {
+    SHUTDOWN_REQUESTED.store(true, Ordering::Release);
+    SHUTDOWN.notify_waiters();
+}
+
+/// Whether a shutdown has been requested.
+pub fn is_shutdown_requested() -> bool {
+    SHUTDOWN_REQUESTED.load(Ordering::Acquire)
+}
+
+/// Wait until a shutdown is requested.
+///
+/// Long-running tasks can select on this to wind down during the grace period.
+pub async fn shutdown_signal() {
+    loop {
+        let notified = SHUTDOWN.notified();
+        let mut notified = pin!(notified);
+        // Register before checking the flag so a concurrent request is not missed
+        notified.as_mut().enable();
+        if is_shutdown_requested() {
+            return;
+        }
+        notified.await;
+    }
+}
+
+/// Wait for Ctrl-C, or `SIGTERM` on Unix.
+async fn os_signal() {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{SignalKind, signal};
+
+        match signal(SignalKind::terminate()) {
+            Ok(mut terminate) => {
+                let ctrl_c = pin!(tokio::signal::ctrl_c());
+                let terminate = pin!(terminate.recv());
+                futures::future::select(ctrl_c, terminate).await;
+            }
+            Err(_) => {
+                let _ = tokio::signal::ctrl_c().await;
+            }
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = tokio::signal::ctrl_c().await;
+    }
+}
+
+/// Run `main` until it completes, giving it `grace` to finish once a
+/// shutdown is requested.
+///
+/// Returns `None` if `main` was cancelled because the grace period ran out.
+pub async fn run_until_shutdown<F: Future>(main: F, grace: Duration) -> Option<F::Output> {
+    let mut main = pin!(main);
+    {
+        let signal = pin!(async {
+            let requested = pin!(shutdown_signal());
+            let os = pin!(os_signal());
+            futures::future::select(requested, os).await;
+        });
+        match futures::future::select(main.as_mut(), signal).await {
+            futures::future::Either::Left((output, _)) => return Some(output),
+            futures::future::Either::Right(((), _)) => request_shutdown(),
+        }
+    }
+    tokio::time::timeout(grace, main).await.ok()
+}
+
+/// Return value of a `#[risten::main]` function cancelled by shutdown.
+pub trait ShutdownOutput {
+    /// The value to return after a graceful shutdown.
+    fn shutdown() -> Self;
+}
+
+impl ShutdownOutput for () {
+    fn shutdown() -> Self {}
+}
+
+impl ShutdownOutput for ExitCode {
+    fn shutdown() -> Self {
+        ExitCode::SUCCESS
+    }
+}
+
+impl<T: ShutdownOutput, E> ShutdownOutput for Result<T, E> {
+    fn shutdown() -> Self {
+        Ok(T::shutdown())
+    }
+}
@@ -3,7 +3,8 @@
 //! This module provides HList-based implementation for compile-time
 //! optimized hook dispatch.
 
-use risten_core::{BoxError, RoutingError, Hook, HookResult, Message, RouteResult, Router};
+use crate::instrument;
+use risten_core::{BoxError, Hook, HookResult, Message, RouteResult, Router, RoutingError};
 
 /// HList terminator - represents an empty hook chain.
 pub struct HNil;
@@ -23,14 +24,16 @@ pub use fanout::{FanoutChain, StaticFanoutRouter};
 /// Trait for dispatching events through a static hook chain.
 pub trait HookChain<E: Message>: Send + Sync + 'static {
     /// Dispatch an event through this chain.
+    ///
+    /// A failing hook is reported as [`RoutingError::Hook`] with its name.
     fn dispatch_chain(
         &self,
         event: &E,
-    ) -> impl std::future::Future<Output = Result<HookResult, BoxError>> + Send;
+    ) -> impl std::future::Future<Output = Result<HookResult, RoutingError>> + Send;
 }
 
 impl<E: Message> HookChain<E> for HNil {
-    async fn dispatch_chain(&self, _event: &E) -> Result<HookResult, BoxError> {
+    async fn dispatch_chain(&self, _event: &E) -> Result<HookResult, RoutingError> {
         Ok(HookResult::Next)
     }
 }
@@ -41,10 +44,12 @@ where
     H: Hook<E>,
     T: HookChain<E>,
 {
-    async fn dispatch_chain(&self, event: &E) -> Result<HookResult, BoxError> {
-        match self.head.on_event(event).await? {
-            HookResult::Stop => Ok(HookResult::Stop),
-            HookResult::Next => self.tail.dispatch_chain(event).await,
+    async fn dispatch_chain(&self, event: &E) -> Result<HookResult, RoutingError> {
+        let name = self.head.name();
+        match instrument::hook(Some(name.clone()), None, self.head.on_event(event)).await {
+            Ok(HookResult::Stop) => Ok(HookResult::Stop),
+            Ok(HookResult::Next) => self.tail.dispatch_chain(event).await,
+            Err(source) => Err(RoutingError::Hook { name, source }),
         }
     }
 }
@@ -107,16 +112,15 @@ where
     type Error = RoutingError;
 
     async fn route(&self, event: &E) -> Result<RouteResult, Self::Error> {
-        let result = self
-            .chain
-            .dispatch_chain(event)
-            .await
-            .map_err(RoutingError::Listener)?;
+        instrument::route(event, async {
+            let result = self.chain.dispatch_chain(event).await?;
 
-        Ok(RouteResult {
-            stopped: result == HookResult::Stop,
-            executed_count: 0, // Static router doesn't track count
+            Ok(RouteResult {
+                stopped: result == HookResult::Stop,
+                executed_count: 0, // Static router doesn't track count
+            })
         })
+        .await
     }
 }
 
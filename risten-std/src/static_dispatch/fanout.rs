@@ -4,9 +4,10 @@
 //! Unlike `StaticRouter` which executes hooks sequentially, `StaticFanoutRouter`
 //! executes all hooks in the chain concurrently.
 
+use crate::instrument;
 use crate::static_dispatch::{HCons, HNil};
 use futures::future::join;
-use risten_core::{BoxError, RoutingError, Hook, HookResult, Message, RouteResult, Router};
+use risten_core::{Hook, HookResult, Message, RouteResult, Router, RoutingError};
 
 /// Result of fanout dispatch including stop tracking.
 pub struct FanoutResult {
@@ -20,11 +21,11 @@ pub trait FanoutChain<E: Message>: Send + Sync + 'static {
     fn dispatch_fanout(
         &self,
         event: &E,
-    ) -> impl std::future::Future<Output = Result<FanoutResult, BoxError>> + Send;
+    ) -> impl std::future::Future<Output = Result<FanoutResult, RoutingError>> + Send;
 }
 
 impl<E: Message> FanoutChain<E> for HNil {
-    async fn dispatch_fanout(&self, _event: &E) -> Result<FanoutResult, BoxError> {
+    async fn dispatch_fanout(&self, _event: &E) -> Result<FanoutResult, RoutingError> {
         Ok(FanoutResult { stopped: false })
     }
 }
@@ -35,8 +36,18 @@ where
     H: Hook<E>,
     T: FanoutChain<E>,
 {
-    async fn dispatch_fanout(&self, event: &E) -> Result<FanoutResult, BoxError> {
-        let head_fut = self.head.on_event(event);
+    async fn dispatch_fanout(&self, event: &E) -> Result<FanoutResult, RoutingError> {
+        let head_fut = async {
+            #[cfg(feature = "metrics")]
+            let _in_flight = crate::metrics::GaugeGuard::new(
+                crate::metrics::FANOUT_IN_FLIGHT,
+                std::any::type_name::<E>(),
+            );
+            let name = self.head.name();
+            instrument::hook(Some(name.clone()), None, self.head.on_event(event))
+                .await
+                .map_err(|source| RoutingError::Hook { name, source })
+        };
         let tail_fut = self.tail.dispatch_fanout(event);
 
         let (head_res, tail_res) = join(head_fut, tail_fut).await;
@@ -76,15 +87,14 @@ where
     type Error = RoutingError;
 
     async fn route(&self, event: &E) -> Result<RouteResult, Self::Error> {
-        let result = self
-            .chain
-            .dispatch_fanout(event)
-            .await
-            .map_err(RoutingError::Listener)?;
-        Ok(RouteResult {
-            stopped: result.stopped,
-            executed_count: 0, // Fanout doesn't track count
+        instrument::route(event, async {
+            let result = self.chain.dispatch_fanout(event).await?;
+            Ok(RouteResult {
+                stopped: result.stopped,
+                executed_count: 0, // Fanout doesn't track count
+            })
         })
+        .await
     }
 }
 
@@ -0,0 +1,72 @@
+//! Poll-scoped task-local values.
+//!
+//! A [`Scoped`] future makes a value current in a thread-local slot for the
+//! duration of each poll of the future it wraps, and restores the previous
+//! value afterwards. The value thus follows the task across `.await` points
+//! and worker threads, without depending on a particular runtime.
+
+use pin_project_lite::pin_project;
+use std::{
+    cell::RefCell,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    thread::LocalKey,
+};
+
+/// A thread-local slot holding the current value, if any.
+pub(crate) type Slot<T> = LocalKey<RefCell<Option<T>>>;
+
+/// The value currently held by `slot` on this thread.
+pub(crate) fn current<T: Clone + 'static>(slot: &'static Slot<T>) -> Option<T> {
+    slot.with(|current| current.borrow().clone())
+}
+
+pin_project! {
+    /// Makes `value` current in `slot` while `future` is polled.
+    pub(crate) struct Scoped<T: 'static, F> {
+        slot: &'static Slot<T>,
+        value: Option<T>,
+        #[pin]
+        future: F,
+    }
+}
+
+impl<T, F> Scoped<T, F> {
+    pub(crate) fn new(slot: &'static Slot<T>, value: Option<T>, future: F) -> Self {
+        Self {
+            slot,
+            value,
+            future,
+        }
+    }
+}
+
+impl<T, F: Future> Future for Scoped<T, F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+
+        /// Swaps the scoped value back out, even if polling panics.
+        struct Restore<'a, T: 'static> {
+            slot: &'static Slot<T>,
+            value: &'a mut Option<T>,
+        }
+
+        impl<T> Drop for Restore<'_, T> {
+            fn drop(&mut self) {
+                self.slot
+                    .with(|current| std::mem::swap(&mut *current.borrow_mut(), self.value));
+            }
+        }
+
+        let slot = *this.slot;
+        slot.with(|current| std::mem::swap(&mut *current.borrow_mut(), this.value));
+        let _restore = Restore {
+            slot,
+            value: this.value,
+        };
+        this.future.poll(cx)
+    }
+}
@@ -9,50 +9,91 @@
 
 pub use risten_core::{
     // Context / Extraction
+    AndThen,
     AsyncFromEvent,
+    BorrowedChain,
+    BorrowedExtractHandler,
+    BorrowedFilter,
+    BorrowedFn,
+    BorrowedListener,
+    BorrowedMap,
+    BorrowedMapFn,
+    BorrowedPipeline,
     // Error types
     BoxError,
+    BoxHandler,
     // Listener (with declarative pipeline methods)
     BoxListener,
+    BoxRouter,
     Catch,
     Chain,
     // Response
     Continue,
+    Contramap,
+    Correlation,
+    // Handler
+    DynHandler,
     // Hook
     DynHook,
     DynListener,
     // Router Traits
     DynRouter,
+    Envelope,
     Event,
+    EventId,
     // Execution Strategy
     ExecutionStrategy,
     ExtractError,
     ExtractHandler,
+    FallbackRouter,
     Filter,
     FilterMap,
     FromEvent,
+    FromEventGat,
     Handled,
-    // Handler
-    DynHandler,
     Handler,
     HandlerResult,
+    Headers,
     Hook,
     HookError,
+    HookFn,
     HookResult,
     IntoHookOutcome,
     IntoResponse,
     Listener,
     Map,
+    MapErr,
+    MapErrRouter,
+    MapEventRouter,
+    MapOutput,
     // Message
     Message,
+    MessageMeta,
+    Metadata,
+    Owned,
+    ParseEventIdError,
     Pipeline,
+    RawMessage,
+    RefEvent,
     RistenError,
     RouteResult,
     Router,
+    RouterExt,
     RouterHook,
     RoutingError,
+    Stateful,
+    SyncBorrowedExtractHandler,
     SyncExtractHandler,
+    TRACEPARENT,
+    TRACESTATE,
     Then,
+    ThenRouter,
+    Timestamp,
+    TraceContext,
+    VariantMessage,
+    When,
+    WithState,
+    hook_fn,
 };
 
 // Static Routing
@@ -66,12 +107,8 @@ pub use risten_std::{
 
 // Dynamic Routing & New Dispatch
 pub use risten_std::{
-    dynamic::{
-        DynamicRouter, HookProvider, Registry, RegistryBuilder, SimpleDynamicDispatcher,
-    },
-    routing::{
-        dispatch::{DispatchRouter, HandlerRegistration, ErasedHandlerWrapper},
-    }
+    dynamic::{DynamicRouter, HookProvider, Registry, RegistryBuilder, SimpleDynamicDispatcher},
+    routing::dispatch::{DispatchRouter, ErasedHandlerWrapper, HandlerRegistration},
 };
 
 /// Dynamic routing support module.
@@ -84,7 +121,40 @@ pub mod dynamic {
 /// Routing components.
 pub mod routing {
     pub use risten_std::routing::{
-        dispatch::{DispatchRouter, HandlerRegistration, ErasedHandlerWrapper},
+        dispatch::{
+            DispatchError, DispatchRouter, ErasedHandlerWrapper, HandlerRegistration, OnceHandler,
+            SequentialDispatchRouter, SourceLocation, Subscriber, Subscription, ValidateRouter,
+            VariantHandler,
+        },
+        emit::{Causation, EmitError, Emitter},
+        nest::NestedRouter,
+    };
+}
+
+/// Task-local context of the event being routed.
+pub mod context {
+    pub use risten_std::context::{
+        DispatchContext, WithContext, current, in_hook, in_route, propagate,
+    };
+}
+
+/// Router and hook metrics.
+#[cfg(feature = "metrics")]
+pub mod metrics {
+    pub use risten_std::metrics::{
+        ANONYMOUS_HOOK, EVENTS_DISPATCHED, EVENTS_FAILED, EVENTS_STOPPED, FANOUT_IN_FLIGHT,
+        HOOK_DURATION, HOOK_ERRORS, HOOK_STOPS, InMemoryRecorder, Labels, MetricsCrateRecorder,
+        MetricsRecorder, QUEUE_DEPTH, ROUTE_DURATION, SetRecorderError, WithRecorder, recorder,
+        set_recorder, with_recorder,
+    };
+}
+
+/// Runtime lifecycle support for `#[risten::main]`.
+#[cfg(feature = "runtime")]
+pub mod runtime {
+    pub use risten_std::runtime::{
+        ShutdownOutput, is_shutdown_requested, request_shutdown, run_until_shutdown,
+        shutdown_signal,
     };
 }
 
@@ -104,6 +174,7 @@ pub mod hooks {
 /// Standard listener implementations.
 pub mod listeners {
     #![allow(clippy::wildcard_imports)]
+    pub use risten_std::listeners::pass_through::PassThrough;
     pub use risten_std::listeners::*;
 }
 
@@ -124,6 +195,11 @@ pub mod prelude {
         BoxListener,
         Catch,
         Chain,
+        // New Router
+        DispatchRouter,
+        DynHandler,
+        // Event Wrapper
+        Event,
         ExtractError,
         Filter,
         FilterMap,
@@ -139,21 +215,35 @@ pub mod prelude {
         Message,
         Pipeline,
         Router,
+        RouterExt,
         RoutingError,
         Then,
-        // New Router
-        DispatchRouter,
-        // Event Wrapper
-        Event,
-        DynHandler,
     };
 
     #[cfg(feature = "macros")]
-    pub use crate::{on, subscribe, handler};
+    pub use crate::{handler, listener, on, subscribe, subscriber};
 }
 
 #[cfg(feature = "macros")]
-pub use risten_macros::{Message, dispatch, event, handler, main, subscribe, on};
+pub use risten_macros::{
+    FromEvent, Message, dispatch, event, handler, listener, main, on, router, subscribe, subscriber,
+};
 
 #[cfg(feature = "inventory")]
 pub use inventory;
+
+/// Support code for the macro expansions. Not public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use risten_core::DerivedVariants;
+    #[cfg(feature = "tracing")]
+    pub use tracing_subscriber;
+
+    /// Report a failed shutdown hook, through `tracing` when it is enabled.
+    pub fn shutdown_hook_failed(hook: &str, err: &dyn std::fmt::Display) {
+        #[cfg(feature = "tracing")]
+        tracing::error!(hook, error = %err, "shutdown hook failed");
+        #[cfg(not(feature = "tracing"))]
+        eprintln!("shutdown hook `{hook}` failed: {err}");
+    }
+}
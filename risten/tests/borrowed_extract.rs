@@ -0,0 +1,167 @@
+//! Tests for zero-copy borrowed extraction.
+
+use risten::{
+    BorrowedExtractHandler, FromEvent, FromEventGat, Handler, Message, Owned, RefEvent,
+    SyncBorrowedExtractHandler,
+};
+use std::convert::Infallible;
+
+#[derive(Clone, Debug)]
+struct ChatEvent {
+    author: String,
+    content: String,
+    channel: u64,
+}
+
+impl Message for ChatEvent {}
+
+/// Borrows the message content.
+struct Content;
+
+impl FromEventGat<ChatEvent> for Content {
+    type Output<'a> = &'a str;
+    type Error = Infallible;
+
+    fn extract(event: &ChatEvent) -> Result<&str, Self::Error> {
+        Ok(&event.content)
+    }
+}
+
+/// Borrows the author name.
+struct Author;
+
+impl FromEventGat<ChatEvent> for Author {
+    type Output<'a> = &'a str;
+    type Error = Infallible;
+
+    fn extract(event: &ChatEvent) -> Result<&str, Self::Error> {
+        Ok(&event.author)
+    }
+}
+
+/// Owned extractor for the channel id.
+struct ChannelId(u64);
+
+impl FromEvent<ChatEvent> for ChannelId {
+    type Error = Infallible;
+
+    fn from_event(event: &ChatEvent) -> Result<Self, Self::Error> {
+        Ok(ChannelId(event.channel))
+    }
+}
+
+fn event() -> ChatEvent {
+    ChatEvent {
+        author: "alice".to_string(),
+        content: "hello".to_string(),
+        channel: 7,
+    }
+}
+
+async fn describe(author: &str, content: &str, channel: ChannelId) -> String {
+    tokio::task::yield_now().await;
+    format!("#{} {}: {}", channel.0, author, content)
+}
+
+async fn content_len(event: RefEvent<'_, ChatEvent>) -> usize {
+    event.0.content.len()
+}
+
+#[tokio::test]
+async fn test_async_borrowed_mixed_arguments() {
+    let handler =
+        BorrowedExtractHandler::<_, ChatEvent, (Author, Content, Owned<ChannelId>)>::new(describe);
+
+    assert_eq!(handler.call(event()).await.unwrap(), "#7 alice: hello");
+}
+
+#[tokio::test]
+async fn test_async_borrowed_ref_event() {
+    let handler =
+        BorrowedExtractHandler::<_, ChatEvent, (RefEvent<'static, ChatEvent>,)>::new(content_len);
+
+    assert_eq!(handler.call(event()).await.unwrap(), 5);
+}
+
+#[tokio::test]
+async fn test_sync_borrowed_closure() {
+    let handler = SyncBorrowedExtractHandler::<_, ChatEvent, (Author, Content)>::new(
+        |author: &str, content: &str| author.len() + content.len(),
+    );
+
+    assert_eq!(handler.call(event()).await.unwrap(), 10);
+}
+
+#[cfg(feature = "macros")]
+mod subscribe {
+    use super::{Author, ChatEvent, Content};
+    use risten::{FromEventGat, Message, Router, routing::DispatchRouter};
+    use std::{
+        convert::Infallible,
+        sync::Mutex,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    #[derive(Clone, Debug)]
+    struct SubscribedEvent(ChatEvent);
+
+    impl Message for SubscribedEvent {}
+
+    // `&str` arguments resolve through `str: FromEventGat<SubscribedEvent>`.
+    impl FromEventGat<SubscribedEvent> for str {
+        type Output<'a> = &'a str;
+        type Error = Infallible;
+
+        fn extract(event: &SubscribedEvent) -> Result<&str, Self::Error> {
+            Ok(&event.0.content)
+        }
+    }
+
+    static CONTENT_LEN: AtomicUsize = AtomicUsize::new(0);
+    static BORROWED_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    #[risten::subscribe]
+    async fn on_borrowed(event: &SubscribedEvent, content: &str) {
+        assert_eq!(event.0.content, content);
+        CONTENT_LEN.fetch_add(content.len(), Ordering::SeqCst);
+    }
+
+    #[risten::subscribe]
+    async fn on_borrowed_event(event: &SubscribedEvent) {
+        let _ = event;
+        BORROWED_CALLS.fetch_add(1, Ordering::SeqCst);
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_borrowed_arguments() {
+        let router = DispatchRouter::<SubscribedEvent>::new();
+        let result = router
+            .route(&SubscribedEvent(super::event()))
+            .await
+            .unwrap();
+
+        assert_eq!(result.executed_count, 2);
+        assert_eq!(CONTENT_LEN.load(Ordering::SeqCst), 5);
+        assert_eq!(BORROWED_CALLS.load(Ordering::SeqCst), 1);
+    }
+
+    static PROJECTED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    // Two `&str` projections of one event, told apart by their extractors
+    #[risten::subscribe(ChatEvent)]
+    async fn on_projected(#[extract(Content)] content: &str, #[extract(Author)] author: &str) {
+        PROJECTED
+            .lock()
+            .unwrap()
+            .push(format!("{author}: {content}"));
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_named_extractors() {
+        let router = DispatchRouter::<ChatEvent>::new();
+        let result = router.route(&super::event()).await.unwrap();
+
+        assert_eq!(result.executed_count, 1);
+        assert_eq!(*PROJECTED.lock().unwrap(), ["alice: hello"]);
+    }
+}
@@ -0,0 +1,110 @@
+//! Tests for the zero-copy BorrowedListener pipeline.
+
+use risten::{
+    BorrowedChain, BorrowedListener, Hook, HookResult, Message, Router, StaticRouter, static_hooks,
+};
+use std::sync::{
+    Mutex,
+    atomic::{AtomicUsize, Ordering},
+};
+
+#[derive(Debug)]
+struct MessageEvent {
+    content: String,
+}
+
+impl Message for MessageEvent {}
+
+/// Projects the message content without cloning.
+struct ContentListener;
+
+impl BorrowedListener<MessageEvent> for ContentListener {
+    type Output<'a> = &'a str;
+
+    fn listen<'a>(&self, event: &'a MessageEvent) -> Option<&'a str> {
+        Some(&event.content)
+    }
+}
+
+/// Strips a `!` command prefix.
+struct CommandListener;
+
+impl BorrowedListener<str> for CommandListener {
+    type Output<'a> = &'a str;
+
+    fn listen<'a>(&self, content: &'a str) -> Option<&'a str> {
+        content.strip_prefix('!')
+    }
+}
+
+fn command_name(command: &str) -> &str {
+    command.split_whitespace().next().unwrap_or_default()
+}
+
+/// Projects the command name, dropping its arguments.
+struct NameListener;
+
+impl BorrowedListener<str> for NameListener {
+    type Output<'a> = &'a str;
+
+    fn listen<'a>(&self, command: &'a str) -> Option<&'a str> {
+        Some(command_name(command))
+    }
+}
+
+static SEEN: Mutex<Vec<String>> = Mutex::new(Vec::new());
+static LENGTHS: AtomicUsize = AtomicUsize::new(0);
+
+async fn record(name: &str) -> HookResult {
+    SEEN.lock().unwrap().push(name.to_string());
+    HookResult::Stop
+}
+
+async fn add_len(len: usize) {
+    LENGTHS.fetch_add(len, Ordering::SeqCst);
+}
+
+fn event(content: &str) -> MessageEvent {
+    MessageEvent {
+        content: content.to_string(),
+    }
+}
+
+#[test]
+fn test_chain_filter_and_map() {
+    let listener = BorrowedChain::new(ContentListener, CommandListener)
+        .filter(|command: &&str| !command.is_empty())
+        .map(command_name);
+
+    let event1 = event("!ping now");
+    assert_eq!(listener.listen(&event1), Some("ping"));
+    assert_eq!(listener.listen(&event("hello")), None);
+    assert_eq!(listener.listen(&event("!")), None);
+}
+
+#[tokio::test]
+async fn test_borrowed_pipeline_as_hook() {
+    let hook = ContentListener
+        .and_then(CommandListener)
+        .and_then(NameListener)
+        .handler(record);
+
+    assert_eq!(
+        hook.on_event(&event("!ban bob")).await.unwrap(),
+        HookResult::Stop
+    );
+    assert_eq!(hook.on_event(&event("hi")).await.unwrap(), HookResult::Next);
+    assert_eq!(*SEEN.lock().unwrap(), vec!["ban".to_string()]);
+}
+
+#[tokio::test]
+async fn test_borrowed_pipeline_in_router() {
+    let lengths = ContentListener
+        .map(|content: &str| content.len())
+        .handler(add_len);
+    let router = StaticRouter::new(static_hooks![lengths]);
+
+    router.route(&event("four")).await.unwrap();
+    router.route(&event("six...")).await.unwrap();
+    assert_eq!(LENGTHS.load(Ordering::SeqCst), 10);
+}
@@ -1,6 +1,7 @@
+use risten::context::{self, DispatchContext};
 use risten::{BoxError, Handler, Hook, HookResult, Listener, Message};
 use std::sync::{
-    Arc, Mutex,
+    Arc, Mutex, MutexGuard,
     atomic::{AtomicUsize, Ordering},
 };
 
@@ -98,3 +99,23 @@ impl Handler<Trigger> for FallibleHandler {
         }
     }
 }
+
+/// Records the dispatch context of every route, after yielding to the runtime.
+#[allow(dead_code)]
+#[derive(Clone, Default)]
+pub struct Inspect(Arc<Mutex<Vec<DispatchContext>>>);
+
+impl Inspect {
+    #[allow(dead_code)]
+    pub fn seen(&self) -> MutexGuard<'_, Vec<DispatchContext>> {
+        self.0.lock().unwrap()
+    }
+}
+
+impl<E: Message> Hook<E> for Inspect {
+    async fn on_event(&self, _event: &E) -> Result<HookResult, BoxError> {
+        tokio::task::yield_now().await;
+        self.seen().push(context::current().unwrap());
+        Ok(HookResult::Next)
+    }
+}
@@ -0,0 +1,102 @@
+//! Tests for the task-local dispatch context.
+
+use risten::context::{self, DispatchContext};
+use risten::{
+    BoxError, Envelope, Hook, HookResult, Message, Router, RouterHook, StaticRouter, static_hooks,
+};
+use std::sync::Mutex;
+
+mod common;
+use common::Inspect;
+
+#[derive(Clone, Debug)]
+struct MessageEvent;
+
+impl Message for MessageEvent {}
+
+#[tokio::test]
+async fn test_context_during_route() {
+    let inspect = Inspect::default();
+
+    assert!(context::current().is_none());
+    let router = StaticRouter::new(static_hooks![inspect.clone()]);
+    router.route(&MessageEvent).await.unwrap();
+    let envelope = Envelope::new(MessageEvent);
+    router.route(&envelope).await.unwrap();
+    assert!(context::current().is_none());
+
+    let seen = inspect.seen();
+    assert_eq!(seen[0].event_type(), std::any::type_name::<MessageEvent>());
+    assert_eq!(seen[0].event_id(), None);
+    assert_eq!(seen[0].hook(), Some(std::any::type_name::<Inspect>()));
+    assert_eq!(seen[0].depth(), 0);
+    assert_eq!(seen[1].event_id(), Some(envelope.metadata().id()));
+}
+
+#[tokio::test]
+async fn test_nested_routes_increase_depth() {
+    let inspect = Inspect::default();
+
+    let inner = StaticRouter::new(static_hooks![inspect.clone()]);
+    let router = StaticRouter::new(static_hooks![RouterHook::new(inner), inspect.clone()]);
+    router.route(&MessageEvent).await.unwrap();
+
+    let depths: Vec<_> = inspect.seen().iter().map(|c| c.depth()).collect();
+    assert_eq!(depths, [1, 0]);
+}
+
+/// Spawns tasks with and without the context.
+struct Spawn(&'static Mutex<Vec<Option<usize>>>);
+
+impl Hook<MessageEvent> for Spawn {
+    async fn on_event(&self, _event: &MessageEvent) -> Result<HookResult, BoxError> {
+        let detached = tokio::spawn(async { context::current().map(|c| c.depth()) });
+        let propagated = tokio::spawn(context::propagate(async {
+            context::current().map(|c| c.depth())
+        }));
+        let mut seen = vec![detached.await.unwrap(), propagated.await.unwrap()];
+        self.0.lock().unwrap().append(&mut seen);
+        Ok(HookResult::Next)
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_spawned_tasks_opt_in() {
+    static SEEN: Mutex<Vec<Option<usize>>> = Mutex::new(Vec::new());
+
+    let router = StaticRouter::new(static_hooks![Spawn(&SEEN)]);
+    router.route(&MessageEvent).await.unwrap();
+
+    assert_eq!(*SEEN.lock().unwrap(), [None, Some(0)]);
+}
+
+#[cfg(feature = "macros")]
+mod dispatch {
+    use super::*;
+    use risten::DispatchRouter;
+
+    #[derive(Clone, Debug)]
+    struct OrderPlaced;
+
+    impl Message for OrderPlaced {}
+
+    static SEEN: Mutex<Vec<DispatchContext>> = Mutex::new(Vec::new());
+
+    #[risten::subscribe(name = "record_order")]
+    async fn on_order(_event: OrderPlaced) {
+        SEEN.lock().unwrap().push(context::current().unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_context_names_dispatch_handler() {
+        DispatchRouter::<OrderPlaced>::new()
+            .route(&OrderPlaced)
+            .await
+            .unwrap();
+
+        let seen = SEEN.lock().unwrap();
+        assert_eq!(seen.len(), 1);
+        assert_eq!(seen[0].hook(), Some("record_order"));
+        assert_eq!(seen[0].event_type(), std::any::type_name::<OrderPlaced>());
+    }
+}
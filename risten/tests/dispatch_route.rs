@@ -0,0 +1,176 @@
+//! Tests for `#[route]` variant attributes and the generated dispatch router.
+
+#![cfg(feature = "macros")]
+
+use risten::{BoxError, Hook, HookResult, Message, Router};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[derive(Clone, Debug)]
+struct MessageEvent {
+    content: String,
+}
+
+impl Message for MessageEvent {}
+
+static LOGGED: AtomicUsize = AtomicUsize::new(0);
+static JOINED: AtomicUsize = AtomicUsize::new(0);
+
+#[risten::event]
+async fn log_message(_event: &MessageEvent) -> Result<HookResult, BoxError> {
+    LOGGED.fetch_add(1, Ordering::SeqCst);
+    Ok(HookResult::Next)
+}
+
+#[risten::event]
+async fn block_empty(event: &MessageEvent) -> Result<HookResult, BoxError> {
+    if event.content.is_empty() {
+        Ok(HookResult::Stop)
+    } else {
+        Ok(HookResult::Next)
+    }
+}
+
+/// Struct and unit variants are routed as the whole enum.
+#[risten::event]
+async fn on_member(event: &ServerEvent) -> Result<HookResult, BoxError> {
+    if let ServerEvent::Join { user, guild } = event {
+        assert_eq!((user.as_str(), *guild), ("alice", 1));
+        JOINED.fetch_add(1, Ordering::SeqCst);
+    }
+    Ok(HookResult::Stop)
+}
+
+/// A configurable hook: the router holds its instance.
+#[derive(Default)]
+struct Counter(AtomicUsize);
+
+impl Hook<ServerEvent> for Counter {
+    async fn on_event(&self, _event: &ServerEvent) -> Result<HookResult, BoxError> {
+        self.0.fetch_add(1, Ordering::SeqCst);
+        Ok(HookResult::Next)
+    }
+}
+
+#[risten::dispatch]
+#[derive(Clone, Debug)]
+enum ServerEvent {
+    #[route(log_message, block_empty)]
+    Message(MessageEvent),
+    #[route(Counter, on_member)]
+    Join {
+        user: String,
+        guild: u64,
+    },
+    #[route(Counter)]
+    Heartbeat,
+    Shutdown,
+}
+
+impl Message for ServerEvent {}
+
+fn message(content: &str) -> ServerEvent {
+    ServerEvent::Message(MessageEvent {
+        content: content.to_string(),
+    })
+}
+
+#[tokio::test]
+async fn test_route_runs_hook_chain_in_order() {
+    let router = ServerEventRouter::new();
+
+    let result = router.route(&message("hi")).await.unwrap();
+    assert!(!result.stopped);
+    let result = router.route(&message("")).await.unwrap();
+    assert!(result.stopped);
+    assert_eq!(LOGGED.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_route_struct_and_unit_variants() {
+    let router = ServerEventRouter::default();
+
+    let join = ServerEvent::Join {
+        user: "alice".to_string(),
+        guild: 1,
+    };
+    assert!(router.route(&join).await.unwrap().stopped);
+    assert!(!router.route(&ServerEvent::Heartbeat).await.unwrap().stopped);
+    assert!(!router.route(&ServerEvent::Shutdown).await.unwrap().stopped);
+
+    // Hook instances live in the router, one chain per variant.
+    assert_eq!(router.join.head.0.load(Ordering::SeqCst), 1);
+    assert_eq!(router.heartbeat.head.0.load(Ordering::SeqCst), 1);
+    assert_eq!(JOINED.load(Ordering::SeqCst), 1);
+}
+
+#[risten::dispatch(router = LifecycleRoutes)]
+enum Lifecycle {
+    #[route(block_empty)]
+    Started(MessageEvent),
+    Stopped,
+}
+
+#[tokio::test]
+async fn test_custom_router_name_without_message_impl() {
+    let routes = LifecycleRoutes::new();
+    let event = Lifecycle::Started(MessageEvent {
+        content: String::new(),
+    });
+    assert_eq!(routes.dispatch(&event).await.unwrap(), HookResult::Stop);
+    assert_eq!(
+        routes.dispatch(&Lifecycle::Stopped).await.unwrap(),
+        HookResult::Next
+    );
+}
+
+#[tokio::test]
+async fn test_dispatch_to_hooks_uses_routes() {
+    let event = Lifecycle::Started(MessageEvent {
+        content: String::new(),
+    });
+    assert_eq!(event.dispatch_to_hooks().await.unwrap(), HookResult::Stop);
+    assert_eq!(
+        Lifecycle::Stopped.dispatch_to_hooks().await.unwrap(),
+        HookResult::Next
+    );
+}
+
+static MOVED: AtomicUsize = AtomicUsize::new(0);
+
+#[risten::event]
+async fn on_move(event: &GameEvent) -> Result<HookResult, BoxError> {
+    if let GameEvent::Move { x } = event {
+        assert_eq!(*x, 1);
+    }
+    MOVED.fetch_add(1, Ordering::SeqCst);
+    Ok(HookResult::Next)
+}
+
+/// Variants named after keywords get raw or suffixed router fields.
+#[risten::dispatch]
+#[derive(Clone, Debug)]
+enum GameEvent {
+    #[route(on_move)]
+    Move { x: i32 },
+    #[route(on_move)]
+    Type,
+    #[route(on_move)]
+    Crate,
+}
+
+impl Message for GameEvent {}
+
+#[tokio::test]
+async fn test_keyword_variants() {
+    let router = GameEventRouter::default();
+    let GameEventRouter {
+        r#move: _,
+        r#type: _,
+        crate_: _,
+    } = &router;
+
+    router.route(&GameEvent::Move { x: 1 }).await.unwrap();
+    router.route(&GameEvent::Type).await.unwrap();
+    router.route(&GameEvent::Crate).await.unwrap();
+    assert_eq!(MOVED.load(Ordering::SeqCst), 3);
+}
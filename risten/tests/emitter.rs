@@ -0,0 +1,121 @@
+//! Tests for follow-up event emission with `Emitter`.
+
+#![cfg(feature = "macros")]
+
+use risten::routing::{Causation, EmitError, Emitter};
+use risten::{DispatchRouter, Message, Router};
+use std::sync::Mutex;
+
+#[derive(Clone, Debug)]
+struct OrderPlaced {
+    id: u32,
+}
+
+impl Message for OrderPlaced {}
+
+#[derive(Clone, Debug)]
+struct SendReceipt {
+    order_id: u32,
+}
+
+impl Message for SendReceipt {}
+
+static RECEIPTS: Mutex<Vec<(u32, Vec<&'static str>)>> = Mutex::new(Vec::new());
+
+#[risten::subscribe]
+async fn on_order(order: &OrderPlaced, emitter: Emitter) -> Result<(), EmitError> {
+    emitter.emit(SendReceipt { order_id: order.id }).await?;
+    Ok(())
+}
+
+#[risten::subscribe]
+async fn send_receipt(receipt: SendReceipt) {
+    let causation = Causation::current().unwrap();
+    RECEIPTS
+        .lock()
+        .unwrap()
+        .push((receipt.order_id, causation.chain().to_vec()));
+}
+
+#[tokio::test]
+async fn test_emit_follow_up_with_causation() {
+    let router = DispatchRouter::<OrderPlaced>::new();
+    router.route(&OrderPlaced { id: 7 }).await.unwrap();
+
+    let receipts = RECEIPTS.lock().unwrap();
+    assert_eq!(receipts.len(), 1);
+    assert_eq!(receipts[0].0, 7);
+    assert_eq!(receipts[0].1, [std::any::type_name::<OrderPlaced>()]);
+    assert!(Causation::current().is_none());
+}
+
+#[derive(Clone, Debug)]
+struct Ping;
+
+impl Message for Ping {}
+
+static PING_ERRORS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+#[risten::subscribe]
+async fn ping_again(_ping: &Ping, emitter: Emitter) {
+    if let Err(err) = emitter.emit(Ping).await {
+        PING_ERRORS.lock().unwrap().push(err.to_string());
+    }
+}
+
+#[tokio::test]
+async fn test_re_emitting_own_type_is_a_loop() {
+    let router = DispatchRouter::<Ping>::new();
+    let result = router.route(&Ping).await.unwrap();
+    assert_eq!(result.executed_count, 1);
+
+    let errors = PING_ERRORS.lock().unwrap();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("would loop"));
+}
+
+#[derive(Clone, Debug)]
+struct Step1;
+
+impl Message for Step1 {}
+
+#[derive(Clone, Debug)]
+struct Step2;
+
+impl Message for Step2 {}
+
+#[derive(Clone, Debug)]
+struct Step3;
+
+impl Message for Step3 {}
+
+#[risten::subscribe]
+async fn step1(_event: &Step1, emitter: Emitter) -> Result<(), EmitError> {
+    emitter.emit(Step2).await?;
+    Ok(())
+}
+
+#[risten::subscribe]
+async fn step2(_event: &Step2, emitter: Emitter) -> Result<(), EmitError> {
+    emitter.with_max_depth(1).emit(Step3).await?;
+    Ok(())
+}
+
+#[risten::subscribe]
+async fn step3(_event: Step3) {}
+
+#[tokio::test]
+async fn test_max_depth() {
+    let err = Emitter::new().emit(Step1).await.unwrap_err();
+
+    // The depth error surfaces through the routing errors of the cascade
+    let mut source: Option<&(dyn std::error::Error + 'static)> = Some(&err);
+    let mut depth_error = None;
+    while let Some(err) = source {
+        if let Some(EmitError::DepthExceeded { max, chain }) = err.downcast_ref::<EmitError>() {
+            depth_error = Some((*max, chain.len()));
+        }
+        source = err.source();
+    }
+    assert_eq!(depth_error, Some((1, 2)));
+}
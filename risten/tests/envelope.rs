@@ -0,0 +1,151 @@
+//! Tests for `Envelope` metadata, its extractors and correlation propagation.
+
+#![cfg(feature = "macros")]
+
+use risten::routing::{EmitError, Emitter};
+use risten::{
+    Correlation, DispatchRouter, Envelope, EventId, Headers, Message, Metadata, ParseEventIdError,
+    Router, Timestamp,
+};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[derive(Clone, Debug)]
+struct OrderPlaced {
+    id: u32,
+}
+
+impl Message for OrderPlaced {}
+
+#[derive(Clone, Debug)]
+struct OrderShipped {
+    id: u32,
+}
+
+impl Message for OrderShipped {}
+
+static PLACED: Mutex<Vec<(EventId, Timestamp, Option<String>)>> = Mutex::new(Vec::new());
+static SHIPPED: Mutex<Vec<(u32, Metadata)>> = Mutex::new(Vec::new());
+
+#[risten::subscribe]
+async fn on_placed(
+    order: &Envelope<OrderPlaced>,
+    id: EventId,
+    timestamp: Timestamp,
+    headers: Headers,
+    emitter: Emitter,
+) -> Result<(), EmitError> {
+    let tenant = headers.get("tenant").map(str::to_string);
+    PLACED.lock().unwrap().push((id, timestamp, tenant));
+    emitter
+        .emit(Envelope::new(OrderShipped { id: order.id }))
+        .await?;
+    Ok(())
+}
+
+#[risten::subscribe]
+async fn on_shipped(shipped: &Envelope<OrderShipped>, correlation: Correlation) {
+    assert_eq!(correlation, shipped.metadata().correlation());
+    SHIPPED
+        .lock()
+        .unwrap()
+        .push((shipped.id, shipped.metadata().clone()));
+}
+
+#[tokio::test]
+async fn test_envelope_extractors_and_correlation() {
+    let placed = Envelope::new(OrderPlaced { id: 7 }).with_header("tenant", "acme");
+    let metadata = placed.metadata().clone();
+    assert_eq!(metadata.correlation().correlation_id, metadata.id());
+    assert_eq!(metadata.correlation().causation_id, None);
+
+    DispatchRouter::<Envelope<OrderPlaced>>::new()
+        .route(&placed)
+        .await
+        .unwrap();
+
+    let placed_log = PLACED.lock().unwrap();
+    assert_eq!(placed_log.len(), 1);
+    assert_eq!(placed_log[0].0, metadata.id());
+    assert_eq!(placed_log[0].1, Timestamp(metadata.timestamp()));
+    assert_eq!(placed_log[0].2.as_deref(), Some("acme"));
+
+    // The follow-up event joined the flow of the order
+    let shipped = SHIPPED.lock().unwrap();
+    assert_eq!(shipped.len(), 1);
+    let (id, shipped_metadata) = &shipped[0];
+    assert_eq!(*id, 7);
+    assert_ne!(shipped_metadata.id(), metadata.id());
+    assert_eq!(
+        shipped_metadata.correlation(),
+        Correlation {
+            correlation_id: metadata.id(),
+            causation_id: Some(metadata.id()),
+        }
+    );
+}
+
+#[test]
+fn test_event_ids_are_unique() {
+    let a = EventId::new();
+    let b = EventId::new();
+    assert_ne!(a, b);
+    assert_eq!(a.to_string().len(), 36);
+    assert_eq!(EventId::from_u128(a.as_u128()), a);
+}
+
+#[test]
+fn test_event_id_parse() {
+    let id = EventId::new();
+    assert_eq!(id.to_string().parse::<EventId>(), Ok(id));
+    assert_eq!(format!("{:032x}", id.as_u128()).parse::<EventId>(), Ok(id));
+
+    for invalid in [
+        "",
+        "not-an-id",
+        "+0000000000000000000000000000000",
+        "0000000-00000-0000-0000-000000000000",
+    ] {
+        assert_eq!(
+            invalid.parse::<EventId>(),
+            Err(ParseEventIdError),
+            "{invalid}"
+        );
+    }
+}
+
+#[test]
+fn test_metadata_round_trip() {
+    let parent = Envelope::new(OrderPlaced { id: 1 });
+    let outgoing = Envelope::caused_by(OrderShipped { id: 1 }, parent.metadata())
+        .with_header("tenant", "acme");
+    let metadata = outgoing.metadata();
+
+    // Encode the metadata as a queue would carry it
+    let correlation = metadata.correlation();
+    let id = metadata.id().to_string();
+    let nanos = metadata
+        .timestamp()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    let correlation_id = correlation.correlation_id.to_string();
+    let causation_id = correlation.causation_id.map(|id| id.to_string());
+    let headers = metadata.headers().clone();
+
+    let rebuilt = Metadata::from_parts(
+        id.parse().unwrap(),
+        UNIX_EPOCH + Duration::from_nanos(nanos.try_into().unwrap()),
+        Correlation {
+            correlation_id: correlation_id.parse().unwrap(),
+            causation_id: causation_id.map(|id| id.parse().unwrap()),
+        },
+        headers,
+    );
+    assert_eq!(&rebuilt, metadata);
+
+    let incoming = Envelope::from_parts(OrderShipped { id: 1 }, rebuilt);
+    assert_eq!(incoming.metadata(), metadata);
+    assert_eq!(incoming.metadata().header("tenant"), Some("acme"));
+    assert!(incoming.metadata().timestamp() <= SystemTime::now());
+}
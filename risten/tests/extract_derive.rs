@@ -0,0 +1,108 @@
+//! Tests for field projection extractors generated by `#[derive(FromEvent)]`.
+
+#![cfg(feature = "macros")]
+
+use risten::{
+    BorrowedExtractHandler, ExtractHandler, FromEvent, FromEventGat, Handler, Message, Router,
+    routing::DispatchRouter,
+};
+use std::sync::Mutex;
+
+#[derive(Clone, Debug, Message, FromEvent)]
+struct MessageEvent {
+    #[extract(Author)]
+    author: String,
+    #[extract(ChannelId)]
+    channel_id: u64,
+    #[extract(Content, borrow = str)]
+    content: String,
+    #[extract(Tags, borrow)]
+    tags: Vec<String>,
+}
+
+fn event() -> MessageEvent {
+    MessageEvent {
+        author: "alice".to_string(),
+        channel_id: 7,
+        content: "hello".to_string(),
+        tags: vec!["greeting".to_string()],
+    }
+}
+
+#[test]
+fn test_owned_projection() {
+    let author = Author::from_event(&event()).unwrap();
+    assert_eq!(author.0, "alice");
+    // Extractors deref to the field
+    assert_eq!(author.len(), 5);
+}
+
+#[test]
+fn test_borrowed_projection() {
+    let event = event();
+    let content: &str = Content::extract(&event).unwrap();
+    assert!(std::ptr::eq(content, event.content.as_str()));
+    let tags: &Vec<String> = Tags::extract(&event).unwrap();
+    assert_eq!(tags, &event.tags);
+}
+
+async fn describe(content: &str, tags: &Vec<String>) -> String {
+    format!("{content} {tags:?}")
+}
+
+#[tokio::test]
+async fn test_projections_in_extract_handlers() {
+    let handler = ExtractHandler::new(|author: Author, channel: ChannelId| async move {
+        format!("#{} {}", *channel, author.0)
+    });
+    assert_eq!(handler.call(event()).await.unwrap(), "#7 alice");
+
+    let handler = BorrowedExtractHandler::<_, MessageEvent, (Content, Tags)>::new(describe);
+    assert_eq!(handler.call(event()).await.unwrap(), "hello [\"greeting\"]");
+}
+
+static SEEN: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+#[risten::subscribe(MessageEvent)]
+async fn on_message(author: Author, content: Content) {
+    SEEN.lock()
+        .unwrap()
+        .push(format!("{}: {}", author.0, content.0));
+}
+
+#[tokio::test]
+async fn test_projections_in_subscribe() {
+    let router = DispatchRouter::<MessageEvent>::new();
+    router.route(&event()).await.unwrap();
+    assert_eq!(*SEEN.lock().unwrap(), ["alice: hello"]);
+}
+
+#[derive(Clone, Debug, Message, FromEvent)]
+struct ReplyEvent {
+    #[extract(ReplyAuthor)]
+    author: String,
+    #[extract(ReplyText, borrow = str)]
+    text: String,
+}
+
+static REPLIES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+// `text` borrows from the event instead of cloning the field
+#[risten::subscribe(ReplyEvent)]
+async fn on_reply(author: ReplyAuthor, #[extract(ReplyText)] text: &str) {
+    REPLIES
+        .lock()
+        .unwrap()
+        .push(format!("{}: {text}", author.0));
+}
+
+#[tokio::test]
+async fn test_borrowed_projection_in_subscribe() {
+    let router = DispatchRouter::<ReplyEvent>::new();
+    let event = ReplyEvent {
+        author: "bob".to_string(),
+        text: "hi".to_string(),
+    };
+    assert_eq!(router.route(&event).await.unwrap().executed_count, 1);
+    assert_eq!(*REPLIES.lock().unwrap(), ["bob: hi"]);
+}
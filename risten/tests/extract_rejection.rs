@@ -0,0 +1,187 @@
+//! Tests for typed extractor rejections.
+
+use risten::routing::SequentialDispatchRouter;
+use risten::{
+    DispatchRouter, ExtractError, ExtractHandler, FromEvent, Handler, HookResult, IntoResponse,
+    Message, Router,
+};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[derive(Clone, Debug)]
+struct ChatEvent {
+    author: Option<String>,
+    content: String,
+}
+
+impl Message for ChatEvent {}
+
+#[derive(Debug)]
+struct MissingAuthor;
+
+impl std::fmt::Display for MissingAuthor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "event has no author")
+    }
+}
+
+impl std::error::Error for MissingAuthor {}
+
+/// Hard extractor: fails when the author is missing.
+struct Author(String);
+
+impl FromEvent<ChatEvent> for Author {
+    type Error = MissingAuthor;
+
+    fn from_event(event: &ChatEvent) -> Result<Self, Self::Error> {
+        event.author.clone().map(Author).ok_or(MissingAuthor)
+    }
+}
+
+/// Soft extractor: skips the handler when the author is missing.
+struct OptionalAuthor(String);
+
+impl FromEvent<ChatEvent> for OptionalAuthor {
+    type Error = MissingAuthor;
+
+    fn from_event(event: &ChatEvent) -> Result<Self, Self::Error> {
+        event
+            .author
+            .clone()
+            .map(OptionalAuthor)
+            .ok_or(MissingAuthor)
+    }
+
+    fn rejection(_error: Self::Error) -> impl IntoResponse {
+        HookResult::Next
+    }
+}
+
+/// Soft extractor: stops the route when the author is missing.
+struct RequiredAuthor;
+
+impl FromEvent<ChatEvent> for RequiredAuthor {
+    type Error = MissingAuthor;
+
+    fn from_event(event: &ChatEvent) -> Result<Self, Self::Error> {
+        event
+            .author
+            .as_ref()
+            .map(|_| RequiredAuthor)
+            .ok_or(MissingAuthor)
+    }
+
+    fn rejection(_error: Self::Error) -> impl IntoResponse {
+        HookResult::Stop
+    }
+}
+
+struct Content(String);
+
+impl FromEvent<ChatEvent> for Content {
+    type Error = std::convert::Infallible;
+
+    fn from_event(event: &ChatEvent) -> Result<Self, Self::Error> {
+        Ok(Content(event.content.clone()))
+    }
+}
+
+fn anonymous() -> ChatEvent {
+    ChatEvent {
+        author: None,
+        content: "hi".to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_hard_rejection_keeps_source_and_position() {
+    let handler = ExtractHandler::new(|_content: Content, _author: Author| async {});
+
+    let err = handler.call(anonymous()).await.unwrap_err();
+
+    assert_eq!(err.index(), Some(1));
+    assert!(err.type_name().unwrap().ends_with("Author"));
+    assert!(!err.is_rejection());
+    assert!(err.downcast_ref::<MissingAuthor>().is_some());
+    assert!(std::error::Error::source(&err).is_some());
+    assert!(err.to_string().contains("argument 1"));
+    assert!(err.into_response().is_err());
+}
+
+#[tokio::test]
+async fn test_soft_rejection_resolves_to_outcome() {
+    let handler = ExtractHandler::new(|_author: OptionalAuthor| async {});
+
+    let result = handler.call(anonymous()).await;
+    let err = result.as_ref().unwrap_err();
+    assert_eq!(err.outcome(), Some(HookResult::Next));
+    assert!(err.downcast_ref::<MissingAuthor>().is_none());
+
+    // The handler output converts into the rejection's outcome, not an error.
+    assert_eq!(result.into_response().unwrap(), HookResult::Next);
+}
+
+#[tokio::test]
+async fn test_successful_extraction() {
+    let handler = ExtractHandler::new(
+        |author: Author, optional: OptionalAuthor, content: Content| async move {
+            assert_eq!(author.0, optional.0);
+            format!("{}: {}", author.0, content.0)
+        },
+    );
+
+    let event = ChatEvent {
+        author: Some("alice".to_string()),
+        content: "hi".to_string(),
+    };
+    assert_eq!(handler.call(event).await.unwrap(), "alice: hi");
+}
+
+#[test]
+fn test_tuple_extractor_preserves_rejection() {
+    let err = <(Content, OptionalAuthor)>::from_event(&anonymous())
+        .err()
+        .unwrap();
+    assert_eq!(err.index(), Some(1));
+    assert_eq!(err.outcome(), Some(HookResult::Next));
+
+    let err = ExtractError::rejected::<(Content, OptionalAuthor)>(
+        0,
+        <(Content, OptionalAuthor)>::rejection(err),
+    );
+    assert!(err.is_rejection());
+}
+
+#[tokio::test]
+async fn test_stop_rejection_stops_route() {
+    static LOWER: AtomicUsize = AtomicUsize::new(0);
+
+    let mut subscription = DispatchRouter::<ChatEvent>::register(
+        ExtractHandler::new(|_author: RequiredAuthor| async {}),
+        10,
+    );
+    subscription.merge(DispatchRouter::<ChatEvent>::register(
+        ExtractHandler::new(|_content: Content| async {
+            LOWER.fetch_add(1, Ordering::SeqCst);
+        }),
+        0,
+    ));
+
+    // Sequential routing skips the lower-priority handler
+    let result = SequentialDispatchRouter::<ChatEvent>::new()
+        .route(&anonymous())
+        .await
+        .unwrap();
+    assert!(result.stopped);
+    assert_eq!(result.executed_count, 0);
+    assert_eq!(LOWER.load(Ordering::SeqCst), 0);
+
+    // Parallel routing has already run every handler, but still stops
+    let result = DispatchRouter::<ChatEvent>::new()
+        .route(&anonymous())
+        .await
+        .unwrap();
+    assert!(result.stopped);
+    assert_eq!(result.executed_count, 1);
+
+    subscription.unregister();
+}
@@ -0,0 +1,88 @@
+//! Tests for handler combinators and `BoxHandler`.
+
+use risten::{BoxHandler, Handler, Message, Stateful};
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone, Debug)]
+struct Order {
+    quantity: u32,
+}
+
+impl Message for Order {}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Total(u32);
+
+impl Message for Total {}
+
+async fn total(order: Order) -> Total {
+    Total(order.quantity * 10)
+}
+
+#[tokio::test]
+async fn test_map_output_and_then() {
+    let handler = total
+        .and_then(|total: Total| async move { Total(total.0 + 5) })
+        .map_output(|total: Total| total.0.to_string());
+
+    assert_eq!(handler.call(Order { quantity: 3 }).await, "35");
+}
+
+#[derive(Debug, PartialEq)]
+struct InvalidOrder(u32);
+
+async fn validate(order: Order) -> Result<u32, &'static str> {
+    if order.quantity == 0 {
+        return Err("empty order");
+    }
+    Ok(order.quantity)
+}
+
+#[tokio::test]
+async fn test_map_err() {
+    let handler = validate.map_err(|_| InvalidOrder(0));
+
+    assert_eq!(handler.call(Order { quantity: 2 }).await, Ok(2));
+    assert_eq!(
+        handler.call(Order { quantity: 0 }).await,
+        Err(InvalidOrder(0))
+    );
+}
+
+#[tokio::test]
+async fn test_with_state() {
+    let ledger = Arc::new(Mutex::new(Vec::new()));
+    let handler = (|input: Stateful<Order, Arc<Mutex<Vec<u32>>>>| async move {
+        input.state.lock().unwrap().push(input.event.quantity);
+    })
+    .with_state(Arc::clone(&ledger));
+
+    handler.call(Order { quantity: 1 }).await;
+    handler.call(Order { quantity: 4 }).await;
+    assert_eq!(*ledger.lock().unwrap(), [1, 4]);
+}
+
+#[tokio::test]
+async fn test_box_handler_collection() {
+    let handlers: Vec<BoxHandler<Order, Total>> = vec![
+        total.boxed(),
+        BoxHandler::new(|order: Order| async move { Total(order.quantity) }),
+        total.map_output(|total: Total| Total(total.0 * 2)).boxed(),
+    ];
+
+    let mut totals = Vec::new();
+    for handler in &handlers {
+        totals.push(handler.call(Order { quantity: 2 }).await);
+    }
+    assert_eq!(totals, [Total(20), Total(2), Total(40)]);
+}
+
+#[test]
+fn test_combinators_forward_name() {
+    let name = std::any::type_name_of_val(&total);
+    let chained = total.and_then(|total: Total| async move { total });
+    assert_eq!(Handler::<Order>::name(&chained), name);
+
+    let mapped = total.map_output(|total: Total| total.0);
+    assert_eq!(Handler::<Order>::name(&mapped), name);
+}
@@ -0,0 +1,156 @@
+//! Tests for the `#[handler]` macro.
+
+#![cfg(feature = "macros")]
+
+use risten::{
+    AsyncFromEvent, BoxError, FromEvent, FromEventGat, Handler, Hook, HookResult, Listener, Message,
+};
+use std::convert::Infallible;
+
+#[derive(Clone, Debug)]
+struct ChatEvent {
+    author: Option<String>,
+    content: String,
+}
+
+impl Message for ChatEvent {}
+
+fn chat(author: Option<&str>, content: &str) -> ChatEvent {
+    ChatEvent {
+        author: author.map(str::to_string),
+        content: content.to_string(),
+    }
+}
+
+#[derive(Debug)]
+struct MissingAuthor;
+
+impl std::fmt::Display for MissingAuthor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "event has no author")
+    }
+}
+
+impl std::error::Error for MissingAuthor {}
+
+/// Sync extractor.
+struct Author(String);
+
+impl FromEvent<ChatEvent> for Author {
+    type Error = MissingAuthor;
+
+    fn from_event(event: &ChatEvent) -> Result<Self, Self::Error> {
+        event.author.clone().map(Author).ok_or(MissingAuthor)
+    }
+}
+
+/// Async extractor.
+struct WordCount(usize);
+
+impl AsyncFromEvent<ChatEvent> for WordCount {
+    type Error = Infallible;
+
+    async fn from_event(event: &ChatEvent) -> Result<Self, Self::Error> {
+        Ok(WordCount(event.content.split_whitespace().count()))
+    }
+}
+
+#[risten::handler]
+async fn summarize(event: &ChatEvent, author: Author, words: WordCount) -> String {
+    format!("{} by {}: {} words", event.content, author.0, words.0)
+}
+
+#[tokio::test]
+async fn test_handler_extracts_sync_and_async_arguments() {
+    let summary = summarize
+        .call(chat(Some("alice"), "hello there"))
+        .await
+        .unwrap();
+    assert_eq!(summary, "hello there by alice: 2 words");
+
+    let err = summarize.call(chat(None, "hi")).await.unwrap_err();
+    assert_eq!(err.index(), Some(1));
+    assert!(err.downcast_ref::<MissingAuthor>().is_some());
+}
+
+#[risten::handler(ChatEvent)]
+fn word_count(words: WordCount) -> usize {
+    words.0
+}
+
+#[tokio::test]
+async fn test_sync_handler_with_explicit_input() {
+    let count = word_count.call(chat(None, "a b c")).await.unwrap();
+    assert_eq!(count, 3);
+}
+
+/// Borrows the content of a chat event.
+struct Text;
+
+impl FromEventGat<ChatEvent> for Text {
+    type Output<'a> = &'a str;
+    type Error = Infallible;
+
+    fn extract(event: &ChatEvent) -> Result<&str, Self::Error> {
+        Ok(&event.content)
+    }
+}
+
+#[risten::handler(ChatEvent)]
+fn shout(#[extract(Text)] content: &str) -> String {
+    content.to_uppercase()
+}
+
+#[tokio::test]
+async fn test_handler_named_extractor() {
+    let shouted = shout.call(chat(None, "hello")).await.unwrap();
+    assert_eq!(shouted, "HELLO");
+}
+
+#[risten::handler]
+async fn is_command(event: &ChatEvent) -> HookResult {
+    if event.content.starts_with('!') {
+        return HookResult::Stop;
+    }
+    HookResult::Next
+}
+
+#[tokio::test]
+async fn test_handler_borrowed_input() {
+    assert_eq!(is_command.call(chat(None, "!ping")).await, HookResult::Stop);
+    assert_eq!(is_command.call(chat(None, "ping")).await, HookResult::Next);
+}
+
+#[risten::handler]
+async fn describe<T: std::fmt::Debug + Message>(input: T) -> String {
+    format!("{:?}", input)
+}
+
+#[tokio::test]
+async fn test_generic_handler() {
+    assert_eq!(describe.call(()).await, "()");
+    assert_eq!(describe.call("hi".to_string()).await, "\"hi\"");
+}
+
+struct Content;
+
+impl Listener<ChatEvent> for Content {
+    type Output = ChatEvent;
+
+    async fn listen(&self, event: &ChatEvent) -> Result<Option<ChatEvent>, BoxError> {
+        Ok(Some(event.clone()))
+    }
+}
+
+#[tokio::test]
+async fn test_handler_in_pipeline_uses_into_response() {
+    let pipeline = Content.handler(is_command);
+    let result = pipeline.on_event(&chat(None, "!ping")).await.unwrap();
+    assert_eq!(result, HookResult::Stop);
+
+    // Extraction failures surface as hook errors.
+    let pipeline = Content.handler(summarize);
+    assert!(pipeline.on_event(&chat(None, "hi")).await.is_err());
+    let result = pipeline.on_event(&chat(Some("bob"), "hi")).await.unwrap();
+    assert_eq!(result, HookResult::Next);
+}
@@ -0,0 +1,112 @@
+//! Tests for closure hooks, blanket `Hook` impls and hook combinators.
+
+use risten::{BoxError, DynHook, Hook, HookResult, Message, hook_fn};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone, Debug)]
+struct MessageEvent {
+    content: String,
+}
+
+impl Message for MessageEvent {}
+
+fn message(content: &str) -> MessageEvent {
+    MessageEvent {
+        content: content.to_string(),
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Envelope {
+    inner: MessageEvent,
+}
+
+impl Message for Envelope {}
+
+/// Counts the events it sees and stops propagation.
+#[derive(Default)]
+struct Counter {
+    seen: AtomicUsize,
+}
+
+impl Hook<MessageEvent> for Counter {
+    async fn on_event(&self, _event: &MessageEvent) -> Result<HookResult, BoxError> {
+        self.seen.fetch_add(1, Ordering::SeqCst);
+        Ok(HookResult::Stop)
+    }
+}
+
+#[tokio::test]
+async fn test_hook_fn() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let hook = hook_fn({
+        let log = Arc::clone(&log);
+        move |event: &MessageEvent| {
+            let log = Arc::clone(&log);
+            let content = event.content.clone();
+            async move {
+                log.lock().unwrap().push(content);
+                Ok(HookResult::Next)
+            }
+        }
+    });
+
+    assert_eq!(
+        hook.on_event(&message("hi")).await.unwrap(),
+        HookResult::Next
+    );
+    assert_eq!(*log.lock().unwrap(), ["hi"]);
+}
+
+#[tokio::test]
+async fn test_blanket_impls() {
+    let counter = Arc::new(Counter::default());
+    assert_eq!(
+        Arc::clone(&counter).on_event(&message("a")).await.unwrap(),
+        HookResult::Stop
+    );
+
+    let none: Option<Counter> = None;
+    assert_eq!(
+        none.on_event(&message("b")).await.unwrap(),
+        HookResult::Next
+    );
+    let some = Some(Arc::clone(&counter));
+    assert_eq!(
+        some.on_event(&message("c")).await.unwrap(),
+        HookResult::Stop
+    );
+
+    let pass = hook_fn(|_: &MessageEvent| async { Ok(HookResult::Next) });
+    let hooks: Vec<Box<dyn DynHook<MessageEvent>>> = vec![
+        pass.boxed(),
+        Arc::clone(&counter).boxed(),
+        Arc::clone(&counter).boxed(),
+    ];
+    assert_eq!(
+        hooks.on_event(&message("d")).await.unwrap(),
+        HookResult::Stop
+    );
+
+    // The second counter is never reached
+    assert_eq!(counter.seen.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn test_when_and_contramap() {
+    let counter = Arc::new(Counter::default());
+    let hook = Arc::clone(&counter)
+        .when(|event: &MessageEvent| event.content.starts_with('!'))
+        .contramap(|envelope: &Envelope| envelope.inner.clone());
+
+    let ping = Envelope {
+        inner: message("!ping"),
+    };
+    let hello = Envelope {
+        inner: message("hello"),
+    };
+    assert_eq!(hook.on_event(&ping).await.unwrap(), HookResult::Stop);
+    assert_eq!(hook.on_event(&hello).await.unwrap(), HookResult::Next);
+    assert_eq!(counter.seen.load(Ordering::SeqCst), 1);
+}
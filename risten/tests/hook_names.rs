@@ -0,0 +1,111 @@
+//! Tests for hook names in routing errors, contexts and registrations.
+
+#![cfg(feature = "macros")]
+
+use risten::context;
+use risten::routing::DispatchError;
+use risten::{
+    BoxError, DispatchRouter, Hook, HookResult, Message, Router, RoutingError, StaticRouter,
+    static_hooks,
+};
+use std::borrow::Cow;
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone, Debug)]
+struct OrderEvent;
+
+impl Message for OrderEvent {}
+
+struct Failing;
+
+impl Hook<OrderEvent> for Failing {
+    async fn on_event(&self, _event: &OrderEvent) -> Result<HookResult, BoxError> {
+        Err("card declined".into())
+    }
+}
+
+/// Records the hook name of the dispatch context, under a custom name.
+struct Charge(&'static Mutex<Vec<String>>);
+
+impl Hook<OrderEvent> for Charge {
+    async fn on_event(&self, _event: &OrderEvent) -> Result<HookResult, BoxError> {
+        let hook = context::current().unwrap().hook().map(str::to_owned);
+        self.0.lock().unwrap().extend(hook);
+        Err("card declined".into())
+    }
+
+    fn name(&self) -> Cow<'static, str> {
+        Cow::Borrowed("charge")
+    }
+}
+
+#[tokio::test]
+async fn test_error_names_failing_hook() {
+    let router = StaticRouter::new(static_hooks![Failing]);
+    let err = router.route(&OrderEvent).await.unwrap_err();
+
+    let name = std::any::type_name::<Failing>();
+    assert!(matches!(&err, RoutingError::Hook { name: n, .. } if n == name));
+    assert_eq!(err.hook_name(), Some(name));
+    assert_eq!(err.to_string(), format!("hook `{name}` failed"));
+    let source = std::error::Error::source(&err).unwrap();
+    assert_eq!(source.to_string(), "card declined");
+}
+
+#[tokio::test]
+async fn test_custom_name() {
+    static SEEN: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    let router = StaticRouter::new(static_hooks![Charge(&SEEN)]);
+    let err = router.route(&OrderEvent).await.unwrap_err();
+    assert_eq!(err.hook_name(), Some("charge"));
+    assert_eq!(*SEEN.lock().unwrap(), ["charge"]);
+}
+
+#[tokio::test]
+async fn test_wrappers_forward_name() {
+    static SEEN: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    let hook = Arc::new(Charge(&SEEN)).when(|_: &OrderEvent| true);
+    assert_eq!(Hook::<OrderEvent>::name(&hook), "charge");
+
+    let router = StaticRouter::new(static_hooks![hook]);
+    let err = router.route(&OrderEvent).await.unwrap_err();
+    assert_eq!(err.hook_name(), Some("charge"));
+}
+
+#[derive(Clone, Debug)]
+struct RefundEvent;
+
+impl Message for RefundEvent {}
+
+#[risten::subscribe]
+async fn refund(_event: RefundEvent) -> Result<(), BoxError> {
+    Err("insufficient funds".into())
+}
+
+#[tokio::test]
+async fn test_handler_error_names_handler() {
+    let router = DispatchRouter::<RefundEvent>::new();
+    let err = router.route(&RefundEvent).await.unwrap_err();
+
+    let DispatchError::Extract(extract) = &err else {
+        panic!("unexpected error: {err:?}");
+    };
+    assert_eq!(extract.handler(), Some("refund"));
+    assert!(err.to_string().starts_with("handler `refund`: "));
+}
+
+#[test]
+fn test_registration_location() {
+    let registration = DispatchRouter::<RefundEvent>::registrations()
+        .next()
+        .unwrap();
+    assert_eq!(registration.handler_name(), "refund");
+
+    let location = registration.location.unwrap();
+    assert_eq!(location.module_path, module_path!());
+    assert_eq!(location.file, file!());
+    assert!(location.line > 0);
+    assert!(location.to_string().starts_with(module_path!()));
+}
@@ -0,0 +1,83 @@
+//! Tests for the `#[listener]` macro.
+
+#![cfg(feature = "macros")]
+
+use risten::{Hook, HookResult, Listener, Message};
+
+#[derive(Clone, Debug)]
+struct MessageEvent {
+    content: String,
+}
+
+impl Message for MessageEvent {}
+
+fn message(content: &str) -> MessageEvent {
+    MessageEvent {
+        content: content.to_string(),
+    }
+}
+
+#[derive(Debug)]
+struct EmptyMessage;
+
+impl std::fmt::Display for EmptyMessage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "message is empty")
+    }
+}
+
+impl std::error::Error for EmptyMessage {}
+
+/// Extracts `!command` names.
+#[risten::listener]
+async fn commands(event: &MessageEvent) -> Option<String> {
+    let command = event.content.strip_prefix('!')?;
+    Some(command.to_string())
+}
+
+#[risten::listener(name = "NonEmpty")]
+fn non_empty(event: &MessageEvent) -> Result<Option<String>, EmptyMessage> {
+    if event.content.is_empty() {
+        return Err(EmptyMessage);
+    }
+    Ok(Some(event.content.clone()))
+}
+
+#[tokio::test]
+async fn test_listener_macro_basic() {
+    let output = commands.listen(&message("!ping")).await.unwrap();
+    assert_eq!(output.as_deref(), Some("ping"));
+
+    let output = commands.listen(&message("ping")).await.unwrap();
+    assert!(output.is_none());
+}
+
+#[tokio::test]
+async fn test_listener_macro_fallible() {
+    let output = NonEmpty.listen(&message("hi")).await.unwrap();
+    assert_eq!(output.as_deref(), Some("hi"));
+
+    let err = NonEmpty.listen(&message("")).await.unwrap_err();
+    assert!(err.downcast_ref::<EmptyMessage>().is_some());
+}
+
+#[tokio::test]
+async fn test_listener_macro_composes() {
+    let pipeline = commands
+        .filter(|command: &String| command != "ignored")
+        .map(|command: String| command.to_uppercase())
+        .handler(|command: String| async move {
+            if command == "SHUTDOWN" {
+                HookResult::Stop
+            } else {
+                HookResult::Next
+            }
+        });
+
+    let result = pipeline.on_event(&message("!shutdown")).await.unwrap();
+    assert_eq!(result, HookResult::Stop);
+    let result = pipeline.on_event(&message("!ignored")).await.unwrap();
+    assert_eq!(result, HookResult::Next);
+    let result = pipeline.on_event(&message("hello")).await.unwrap();
+    assert_eq!(result, HookResult::Next);
+}
@@ -0,0 +1,162 @@
+//! Tests for the configurable `#[risten::main]` macro.
+
+#![cfg(all(feature = "macros", feature = "runtime"))]
+
+use risten::routing::{Subscriber, ValidateRouter};
+use risten::runtime::{is_shutdown_requested, request_shutdown, shutdown_signal};
+use risten::{BoxError, DispatchRouter, Message, Router};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+#[derive(Clone, Debug)]
+struct AppEvent;
+
+impl Message for AppEvent {}
+
+static HANDLED: AtomicUsize = AtomicUsize::new(0);
+
+#[risten::subscribe]
+async fn on_app_event(_event: AppEvent) {
+    HANDLED.fetch_add(1, Ordering::SeqCst);
+}
+
+static TRACING: AtomicBool = AtomicBool::new(false);
+static STARTED: AtomicBool = AtomicBool::new(false);
+static FLUSHED: AtomicBool = AtomicBool::new(false);
+
+fn init_tracing() {
+    TRACING.store(true, Ordering::SeqCst);
+}
+
+async fn connect() -> Result<(), BoxError> {
+    assert!(TRACING.load(Ordering::SeqCst));
+    STARTED.store(true, Ordering::SeqCst);
+    Ok(())
+}
+
+async fn flush() -> Result<(), BoxError> {
+    FLUSHED.store(true, Ordering::SeqCst);
+    Ok(())
+}
+
+#[risten::main(
+    flavor = "multi_thread",
+    workers = 2,
+    tracing = init_tracing,
+    startup = connect,
+    shutdown = flush,
+    graceful_shutdown = "20ms",
+)]
+async fn app(router: DispatchRouter<AppEvent>) -> Result<(), BoxError> {
+    assert!(STARTED.load(Ordering::SeqCst));
+    router.route(&AppEvent).await?;
+
+    request_shutdown();
+    shutdown_signal().await;
+
+    // Never finishes on its own; cancelled once the grace period runs out
+    std::future::pending::<()>().await;
+    Ok(())
+}
+
+#[test]
+fn test_main_lifecycle_and_graceful_shutdown() {
+    app().unwrap();
+
+    assert_eq!(HANDLED.load(Ordering::SeqCst), 1);
+    assert!(is_shutdown_requested());
+    assert!(FLUSHED.load(Ordering::SeqCst));
+}
+
+#[derive(Clone, Debug)]
+struct UnhandledEvent;
+
+impl Message for UnhandledEvent {}
+
+#[risten::main(flavor = "current_thread", router = DispatchRouter<UnhandledEvent>)]
+async fn unhandled_app() {}
+
+#[test]
+#[should_panic(expected = "no handlers registered")]
+fn test_main_validates_router() {
+    unhandled_app();
+}
+
+#[derive(Clone, Debug)]
+struct LateEvent;
+
+impl Message for LateEvent {}
+
+/// Only subscribes at runtime, from a startup hook.
+#[derive(Default)]
+struct LateService {
+    handled: AtomicUsize,
+}
+
+#[risten::subscriber]
+impl LateService {
+    #[on]
+    async fn on_late(&self, _event: LateEvent) {
+        self.handled.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+static LATE: std::sync::OnceLock<Arc<LateService>> = std::sync::OnceLock::new();
+
+async fn subscribe_late() -> Result<(), BoxError> {
+    let service = Arc::clone(LATE.get_or_init(Default::default));
+    // Stays registered for the whole run
+    let _ = service.subscribe();
+    Ok(())
+}
+
+#[risten::main(flavor = "current_thread", startup = subscribe_late)]
+async fn late_app(router: DispatchRouter<LateEvent>) -> Result<(), BoxError> {
+    router.route(&LateEvent).await?;
+    Ok(())
+}
+
+#[test]
+fn test_main_validates_after_startup() {
+    late_app().unwrap();
+    assert_eq!(LATE.get().unwrap().handled.load(Ordering::SeqCst), 1);
+}
+
+#[derive(Clone, Debug)]
+struct AuditEvent;
+
+impl Message for AuditEvent {}
+
+#[derive(Default)]
+struct Auditor;
+
+#[risten::subscriber]
+impl Auditor {
+    #[on]
+    async fn on_audit(&self, _event: AuditEvent) {}
+}
+
+#[test]
+fn test_validate_allows_subscriber_instances() {
+    let router = DispatchRouter::<AuditEvent>::new();
+    let mut subscription = Arc::new(Auditor).subscribe();
+    subscription.merge(Arc::new(Auditor).subscribe());
+    assert!(router.validate().is_ok());
+    subscription.unregister();
+}
+
+#[cfg(feature = "tracing")]
+#[risten::main(flavor = "current_thread", tracing, shutdown = fail_flush)]
+async fn traced_app() {}
+
+#[cfg(feature = "tracing")]
+async fn fail_flush() -> Result<(), BoxError> {
+    Err("disk full".into())
+}
+
+#[cfg(feature = "tracing")]
+#[test]
+fn test_main_default_tracing() {
+    traced_app();
+    assert!(tracing::dispatcher::has_been_set());
+}
@@ -0,0 +1,70 @@
+//! Tests for `MessageMeta` generated by `#[derive(Message)]`.
+
+#![cfg(feature = "macros")]
+
+use risten::{Message, MessageMeta};
+
+#[derive(Clone, Debug, Message)]
+#[message(name = "chat.message", topic = "chat", key = channel_id)]
+struct MessageEvent {
+    channel_id: u64,
+    content: String,
+}
+
+#[derive(Clone, Debug, Message)]
+struct ReadyEvent;
+
+#[derive(Clone, Debug, Message)]
+#[message(key = 0)]
+struct UserEvent(String);
+
+#[derive(Clone, Debug, Message)]
+#[message(name = "gateway", topic = "gateway")]
+enum GatewayEvent {
+    Message(MessageEvent),
+    Ready,
+}
+
+/// Generic code can describe events without `std::any::type_name`.
+fn describe<E: MessageMeta>(event: &E) -> String {
+    format!(
+        "{}@{}#{}",
+        E::NAME,
+        E::TOPIC.unwrap_or("-"),
+        event.key().unwrap_or_default()
+    )
+}
+
+#[test]
+fn test_message_meta_attributes() {
+    let event = MessageEvent {
+        channel_id: 42,
+        content: "hi".to_string(),
+    };
+    assert_eq!(event.content, "hi");
+    assert_eq!(describe(&event), "chat.message@chat#42");
+}
+
+#[test]
+fn test_message_meta_defaults() {
+    assert_eq!(ReadyEvent::NAME, "ReadyEvent");
+    assert_eq!(ReadyEvent::TOPIC, None);
+    assert_eq!(ReadyEvent.key(), None);
+
+    let event = UserEvent("alice".to_string());
+    assert_eq!(describe(&event), "UserEvent@-#alice");
+}
+
+#[test]
+fn test_message_meta_on_enum() {
+    assert_eq!(describe(&GatewayEvent::Ready), "gateway@gateway#");
+    assert_eq!(GatewayEvent::Ready.variant(), Some("Ready"));
+    let event = GatewayEvent::Message(MessageEvent {
+        channel_id: 1,
+        content: String::new(),
+    });
+    assert_eq!(event.variant(), Some("Message"));
+    if let GatewayEvent::Message(inner) = &event {
+        assert_eq!(inner.key().as_deref(), Some("1"));
+    }
+}
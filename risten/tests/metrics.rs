@@ -0,0 +1,179 @@
+//! Tests for router and hook metrics.
+
+#![cfg(feature = "metrics")]
+
+use risten::metrics::{
+    EVENTS_DISPATCHED, EVENTS_FAILED, EVENTS_STOPPED, FANOUT_IN_FLIGHT, HOOK_DURATION, HOOK_ERRORS,
+    HOOK_STOPS, InMemoryRecorder, ROUTE_DURATION, with_recorder,
+};
+use risten::routing::NestedRouter;
+use risten::{
+    BoxError, Hook, HookResult, Message, Router, StaticFanoutRouter, StaticRouter, static_fanout,
+    static_hooks,
+};
+use std::any::type_name;
+use std::sync::Mutex;
+
+#[derive(Clone, Debug)]
+struct MessageEvent;
+
+impl Message for MessageEvent {}
+
+struct Pass;
+
+impl Hook<MessageEvent> for Pass {
+    async fn on_event(&self, _event: &MessageEvent) -> Result<HookResult, BoxError> {
+        Ok(HookResult::Next)
+    }
+}
+
+struct Consume;
+
+impl Hook<MessageEvent> for Consume {
+    async fn on_event(&self, _event: &MessageEvent) -> Result<HookResult, BoxError> {
+        Ok(HookResult::Stop)
+    }
+}
+
+struct Fail;
+
+impl Hook<MessageEvent> for Fail {
+    async fn on_event(&self, _event: &MessageEvent) -> Result<HookResult, BoxError> {
+        Err("boom".into())
+    }
+}
+
+fn event() -> (&'static str, &'static str) {
+    ("event", type_name::<MessageEvent>())
+}
+
+#[tokio::test]
+async fn test_route_and_hook_metrics() {
+    let recorder = InMemoryRecorder::new();
+    let router = StaticRouter::new(static_hooks![Pass, Consume, Pass]);
+    with_recorder(recorder.clone(), async {
+        router.route(&MessageEvent).await.unwrap();
+        router.route(&MessageEvent).await.unwrap();
+    })
+    .await;
+
+    assert_eq!(recorder.counter(EVENTS_DISPATCHED, &[event()]), 2);
+    assert_eq!(recorder.counter(EVENTS_STOPPED, &[event()]), 2);
+    assert_eq!(recorder.counter(EVENTS_FAILED, &[event()]), 0);
+    assert_eq!(recorder.histogram(ROUTE_DURATION, &[event()]).len(), 2);
+
+    let pass = ("hook", type_name::<Pass>());
+    let consume = ("hook", type_name::<Consume>());
+    assert_eq!(recorder.histogram(HOOK_DURATION, &[event(), pass]).len(), 2);
+    assert_eq!(
+        recorder.histogram(HOOK_DURATION, &[event(), consume]).len(),
+        2
+    );
+    assert_eq!(recorder.counter(HOOK_STOPS, &[consume]), 2);
+    assert_eq!(recorder.counter(HOOK_STOPS, &[pass]), 0);
+}
+
+#[tokio::test]
+async fn test_error_metrics() {
+    let recorder = InMemoryRecorder::new();
+    let router = StaticRouter::new(static_hooks![Pass, Fail]);
+    with_recorder(recorder.clone(), async {
+        assert!(router.route(&MessageEvent).await.is_err());
+    })
+    .await;
+
+    assert_eq!(recorder.counter(EVENTS_DISPATCHED, &[event()]), 1);
+    assert_eq!(recorder.counter(EVENTS_FAILED, &[event()]), 1);
+    assert_eq!(
+        recorder.counter(HOOK_ERRORS, &[event(), ("hook", type_name::<Fail>())]),
+        1
+    );
+    assert_eq!(recorder.counter(HOOK_ERRORS, &[]), 1);
+}
+
+#[tokio::test]
+async fn test_nested_route_counted_once() {
+    let recorder = InMemoryRecorder::new();
+    let router = NestedRouter::new(|_: &MessageEvent| Some("chat"))
+        .mount("chat", StaticRouter::new(static_hooks![Consume]));
+    with_recorder(recorder.clone(), async {
+        router.route(&MessageEvent).await.unwrap();
+    })
+    .await;
+
+    assert_eq!(recorder.counter(EVENTS_DISPATCHED, &[event()]), 1);
+    assert_eq!(recorder.counter(EVENTS_STOPPED, &[event()]), 1);
+    assert_eq!(recorder.histogram(ROUTE_DURATION, &[event()]).len(), 1);
+}
+
+#[tokio::test]
+async fn test_no_recorder_records_nothing() {
+    let recorder = InMemoryRecorder::new();
+    let router = StaticRouter::new(static_hooks![Pass]);
+    router.route(&MessageEvent).await.unwrap();
+    assert_eq!(recorder.counter(EVENTS_DISPATCHED, &[]), 0);
+}
+
+/// Records the fan-out gauge while running.
+struct Observe(InMemoryRecorder, &'static Mutex<Vec<f64>>);
+
+impl Hook<MessageEvent> for Observe {
+    async fn on_event(&self, _event: &MessageEvent) -> Result<HookResult, BoxError> {
+        tokio::task::yield_now().await;
+        let in_flight = self.0.gauge(FANOUT_IN_FLIGHT, &[event()]);
+        self.1.lock().unwrap().push(in_flight);
+        Ok(HookResult::Next)
+    }
+}
+
+#[tokio::test]
+async fn test_fanout_in_flight() {
+    static SEEN: Mutex<Vec<f64>> = Mutex::new(Vec::new());
+
+    let recorder = InMemoryRecorder::new();
+    let router = StaticFanoutRouter::new(static_fanout![
+        Observe(recorder.clone(), &SEEN),
+        Observe(recorder.clone(), &SEEN),
+    ]);
+    with_recorder(recorder.clone(), router.route(&MessageEvent))
+        .await
+        .unwrap();
+
+    // Both hooks are running when the first one resumes
+    assert_eq!(SEEN.lock().unwrap()[0], 2.0);
+    assert_eq!(recorder.gauge(FANOUT_IN_FLIGHT, &[event()]), 0.0);
+}
+
+#[cfg(feature = "resilience")]
+#[tokio::test]
+async fn test_queue_depth() {
+    use risten::hooks::concurrency::ConcurrencyLimitHook;
+    use risten::metrics::QUEUE_DEPTH;
+
+    static SEEN: Mutex<Vec<f64>> = Mutex::new(Vec::new());
+
+    struct Depth(InMemoryRecorder);
+
+    impl Hook<MessageEvent> for Depth {
+        async fn on_event(&self, _event: &MessageEvent) -> Result<HookResult, BoxError> {
+            tokio::task::yield_now().await;
+            SEEN.lock()
+                .unwrap()
+                .push(self.0.gauge(QUEUE_DEPTH, &[event()]));
+            Ok(HookResult::Next)
+        }
+    }
+
+    let recorder = InMemoryRecorder::new();
+    let hook = ConcurrencyLimitHook::new(Depth(recorder.clone()), 1);
+    with_recorder(recorder.clone(), async {
+        let (first, second) =
+            futures::future::join(hook.on_event(&MessageEvent), hook.on_event(&MessageEvent)).await;
+        first.unwrap();
+        second.unwrap();
+    })
+    .await;
+
+    assert_eq!(*SEEN.lock().unwrap(), [1.0, 0.0]);
+    assert_eq!(recorder.gauge(QUEUE_DEPTH, &[]), 0.0);
+}
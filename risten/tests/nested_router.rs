@@ -0,0 +1,151 @@
+//! Tests for `NestedRouter` mount points.
+
+use risten::routing::NestedRouter;
+use risten::{BoxError, Hook, HookResult, Message, Router, StaticRouter, static_hooks};
+use std::sync::Mutex;
+
+#[derive(Clone, Debug)]
+struct Command {
+    path: String,
+}
+
+impl Message for Command {}
+
+impl Command {
+    fn new(path: &str) -> Self {
+        Command {
+            path: path.to_string(),
+        }
+    }
+
+    fn head(&self) -> Option<String> {
+        self.path.split('/').next().map(str::to_string)
+    }
+
+    /// The command with its first segment removed.
+    fn strip_head(&self) -> Command {
+        let rest = self.path.split_once('/').map_or("", |(_, rest)| rest);
+        Command::new(rest)
+    }
+}
+
+/// Records the command path under its label and stops propagation.
+struct Record(&'static str, &'static Mutex<Vec<String>>);
+
+impl Hook<Command> for Record {
+    async fn on_event(&self, event: &Command) -> Result<HookResult, BoxError> {
+        self.1
+            .lock()
+            .unwrap()
+            .push(format!("{}:{}", self.0, event.path));
+        Ok(HookResult::Stop)
+    }
+}
+
+#[tokio::test]
+async fn test_mounts_forward_matching_events() {
+    static LOG: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    let music = NestedRouter::new(Command::head).mount(
+        "play".to_string(),
+        StaticRouter::new(static_hooks![Record("play", &LOG)]),
+    );
+    let root = NestedRouter::new(Command::head)
+        .mount(
+            "admin".to_string(),
+            StaticRouter::new(static_hooks![Record("admin", &LOG)]),
+        )
+        .mount_with("music".to_string(), Command::strip_head, music);
+
+    assert!(root.is_mounted(&"music".to_string()));
+    assert!(
+        root.route(&Command::new("admin/ban"))
+            .await
+            .unwrap()
+            .stopped
+    );
+    assert!(
+        root.route(&Command::new("music/play/song"))
+            .await
+            .unwrap()
+            .stopped
+    );
+
+    // Nothing mounted under the key
+    let result = root.route(&Command::new("weather")).await.unwrap();
+    assert_eq!(result.executed_count, 0);
+    let result = root.route(&Command::new("music/stop")).await.unwrap();
+    assert!(!result.stopped);
+
+    assert_eq!(*LOG.lock().unwrap(), ["admin:admin/ban", "play:play/song"]);
+}
+
+#[tokio::test]
+async fn test_otherwise_receives_unmatched_events() {
+    static LOG: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    let root = NestedRouter::new(Command::head)
+        .mount(
+            "admin".to_string(),
+            StaticRouter::new(static_hooks![Record("admin", &LOG)]),
+        )
+        .otherwise(StaticRouter::new(static_hooks![Record("unknown", &LOG)]));
+
+    root.route(&Command::new("admin")).await.unwrap();
+    root.route(&Command::new("dance")).await.unwrap();
+
+    assert_eq!(*LOG.lock().unwrap(), ["admin:admin", "unknown:dance"]);
+}
+
+#[derive(Clone, Debug)]
+enum GuildEvent {
+    Join(String),
+    Leave(String),
+}
+
+impl Message for GuildEvent {
+    fn variant(&self) -> Option<&'static str> {
+        Some(match self {
+            GuildEvent::Join(_) => "Join",
+            GuildEvent::Leave(_) => "Leave",
+        })
+    }
+}
+
+#[tokio::test]
+async fn test_mount_by_variant() {
+    static LOG: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    let root = NestedRouter::<GuildEvent, _>::by_variant().mount_with(
+        "Join",
+        |event: &GuildEvent| match event {
+            GuildEvent::Join(user) | GuildEvent::Leave(user) => Command::new(user),
+        },
+        StaticRouter::new(static_hooks![Record("joined", &LOG)]),
+    );
+
+    root.route(&GuildEvent::Join("alice".to_string()))
+        .await
+        .unwrap();
+    root.route(&GuildEvent::Leave("bob".to_string()))
+        .await
+        .unwrap();
+
+    assert_eq!(*LOG.lock().unwrap(), ["joined:alice"]);
+}
+
+#[test]
+#[should_panic(expected = "already mounted")]
+fn test_duplicate_mount_panics() {
+    static LOG: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    let _ = NestedRouter::new(Command::head)
+        .mount(
+            "admin".to_string(),
+            StaticRouter::new(static_hooks![Record("a", &LOG)]),
+        )
+        .mount(
+            "admin".to_string(),
+            StaticRouter::new(static_hooks![Record("b", &LOG)]),
+        );
+}
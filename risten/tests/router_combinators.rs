@@ -0,0 +1,109 @@
+//! Tests for `RouterExt` combinators and `BoxRouter`.
+
+use risten::{
+    BoxError, BoxRouter, Hook, HookResult, Message, RouteResult, Router, RouterExt, RoutingError,
+    StaticRouter, static_hooks,
+};
+use std::sync::Mutex;
+
+#[derive(Clone, Debug)]
+struct MessageEvent {
+    content: String,
+}
+
+impl Message for MessageEvent {}
+
+fn message(content: &str) -> MessageEvent {
+    MessageEvent {
+        content: content.to_string(),
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Envelope {
+    inner: MessageEvent,
+}
+
+impl Message for Envelope {}
+
+/// Records the event under its label; stops propagation for commands.
+struct Record(&'static str, &'static Mutex<Vec<String>>);
+
+impl Hook<MessageEvent> for Record {
+    async fn on_event(&self, event: &MessageEvent) -> Result<HookResult, BoxError> {
+        self.1
+            .lock()
+            .unwrap()
+            .push(format!("{}:{}", self.0, event.content));
+        if event.content.starts_with('!') {
+            Ok(HookResult::Stop)
+        } else {
+            Ok(HookResult::Next)
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("router is down")]
+struct Down;
+
+/// A router that always fails.
+struct Broken;
+
+impl Router<MessageEvent> for Broken {
+    type Error = Down;
+
+    async fn route(&self, _event: &MessageEvent) -> Result<RouteResult, Self::Error> {
+        Err(Down)
+    }
+}
+
+#[tokio::test]
+async fn test_then_and_fallback() {
+    static LOG: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    let router = StaticRouter::new(static_hooks![Record("commands", &LOG)])
+        .fallback(StaticRouter::new(static_hooks![Record("chat", &LOG)]))
+        .then(StaticRouter::new(static_hooks![Record("audit", &LOG)]));
+
+    let result = router.route(&message("!ping")).await.unwrap();
+    assert!(result.stopped);
+    router.route(&message("hello")).await.unwrap();
+
+    assert_eq!(
+        *LOG.lock().unwrap(),
+        [
+            "commands:!ping",
+            "audit:!ping",
+            "commands:hello",
+            "chat:hello",
+            "audit:hello",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_map_event_map_err_and_boxed() {
+    static LOG: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    let router = StaticRouter::new(static_hooks![Record("inner", &LOG)])
+        .map_event(|envelope: &Envelope| envelope.inner.clone());
+    let envelope = Envelope {
+        inner: message("hi"),
+    };
+    router.route(&envelope).await.unwrap();
+    assert_eq!(*LOG.lock().unwrap(), ["inner:hi"]);
+
+    let mapped = Broken.map_err(|err| RoutingError::Listener(Box::new(err)));
+    let err = mapped.route(&message("hi")).await.unwrap_err();
+    assert!(matches!(err, RoutingError::Listener(_)));
+
+    // Routers with different error types side by side
+    let routers: Vec<BoxRouter<MessageEvent>> = vec![
+        StaticRouter::new(static_hooks![Record("boxed", &LOG)]).boxed(),
+        Broken.boxed(),
+    ];
+    assert!(routers[0].route(&message("a")).await.is_ok());
+    let err = routers[1].route(&message("b")).await.unwrap_err();
+    assert_eq!(err.to_string(), "router is down");
+}
@@ -0,0 +1,120 @@
+//! Tests for the `router!` DSL.
+
+#![cfg(feature = "macros")]
+
+use risten::{BoxError, Hook, HookResult, Listener, Message, Router};
+use std::sync::Mutex;
+
+#[derive(Clone, Debug)]
+struct MessageEvent {
+    content: String,
+}
+
+impl Message for MessageEvent {}
+
+fn message(content: &str) -> MessageEvent {
+    MessageEvent {
+        content: content.to_string(),
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Command {
+    name: String,
+}
+
+impl Message for Command {}
+
+static LOG: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+fn log(entry: String) {
+    LOG.lock().unwrap().push(entry);
+}
+
+struct Logging;
+
+impl Hook<MessageEvent> for Logging {
+    async fn on_event(&self, event: &MessageEvent) -> Result<HookResult, BoxError> {
+        log(format!("log:{}", event.content));
+        Ok(HookResult::Next)
+    }
+}
+
+fn is_command(event: &MessageEvent) -> bool {
+    event.content.starts_with('!')
+}
+
+fn parse(event: MessageEvent) -> Command {
+    Command {
+        name: event.content[1..].to_string(),
+    }
+}
+
+async fn handle_command(command: Command) -> HookResult {
+    log(format!("command:{}", command.name));
+    HookResult::Stop
+}
+
+struct Ignore;
+
+impl Hook<MessageEvent> for Ignore {
+    async fn on_event(&self, event: &MessageEvent) -> Result<HookResult, BoxError> {
+        log(format!("ignored:{}", event.content));
+        Ok(HookResult::Stop)
+    }
+}
+
+#[tokio::test]
+async fn test_router_dsl_routes_in_order() {
+    let router = risten::router! {
+        MessageEvent => [
+            Logging,
+            filter(is_command) => map(parse) => handle_command,
+            fallback => Ignore,
+        ]
+    };
+
+    let result = router.route(&message("!ping")).await.unwrap();
+    assert!(result.stopped);
+    let result = router.route(&message("hello")).await.unwrap();
+    assert!(result.stopped);
+
+    assert_eq!(
+        *LOG.lock().unwrap(),
+        ["log:!ping", "command:ping", "log:hello", "ignored:hello"]
+    );
+}
+
+/// Upper-cases the message content.
+struct Shout;
+
+impl Listener<MessageEvent> for Shout {
+    type Output = MessageEvent;
+
+    async fn listen(&self, event: &MessageEvent) -> Result<Option<MessageEvent>, BoxError> {
+        Ok(Some(message(&event.content.to_uppercase())))
+    }
+}
+
+#[tokio::test]
+async fn test_router_dsl_listener_stages_and_closures() {
+    static SEEN: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    async fn record(event: MessageEvent) {
+        SEEN.lock().unwrap().push(event.content);
+    }
+
+    let router = risten::router! {
+        MessageEvent => [
+            Shout => filter(|event| event.content.len() > 2) => record,
+            filter(|event| event.content.is_empty()) => then(|event| async move { event }) => record,
+        ]
+    };
+
+    let result = router.route(&message("hi")).await.unwrap();
+    assert!(!result.stopped);
+    router.route(&message("hey")).await.unwrap();
+    router.route(&message("")).await.unwrap();
+
+    assert_eq!(*SEEN.lock().unwrap(), ["HEY", ""]);
+}
@@ -7,13 +7,13 @@
 //! - RouteResult tracking
 
 use risten::{
-    routing::{DispatchRouter, ErasedHandlerWrapper},
     ExtractError, Handler, Message, Router,
+    routing::{DispatchRouter, ErasedHandlerWrapper},
 };
 use std::any::TypeId;
 use std::sync::{
-    atomic::{AtomicUsize, Ordering},
     Arc,
+    atomic::{AtomicUsize, Ordering},
 };
 
 mod common;
@@ -145,6 +145,10 @@ async fn test_manual_handler_registration() {
             type_id: TypeId::of::<ManualEvent>(),
             handler: &HANDLER_WRAPPER,
             priority: 0,
+            variant: None,
+            name: None,
+            tags: &[],
+            location: None,
         }
     }
 
@@ -159,7 +163,7 @@ async fn test_manual_handler_registration() {
 /// Test the static router still works after refactoring.
 #[tokio::test]
 async fn test_static_router_still_works() {
-    use risten::{static_hooks, Hook, HookResult, StaticRouter};
+    use risten::{Hook, HookResult, StaticRouter, static_hooks};
 
     struct SimpleHook;
     impl Hook<common::TestEvent> for SimpleHook {
@@ -186,7 +190,7 @@ async fn test_static_router_still_works() {
 /// Test the static fanout router for parallel execution.
 #[tokio::test]
 async fn test_static_fanout_router() {
-    use risten::{static_fanout, Hook, HookResult, StaticFanoutRouter};
+    use risten::{Hook, HookResult, StaticFanoutRouter, static_fanout};
     use std::sync::atomic::{AtomicUsize, Ordering};
 
     static CALL_COUNT: AtomicUsize = AtomicUsize::new(0);
@@ -0,0 +1,139 @@
+//! Tests for the `filter`, `name`, `tags` and `once` options of `#[subscribe]`.
+
+#![cfg(feature = "macros")]
+
+use risten::routing::OnceHandler;
+use risten::{DispatchRouter, ExtractError, FromEvent, Handler, Message, Router};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[derive(Clone, Debug)]
+struct AdminCommand {
+    user: String,
+    command: String,
+}
+
+impl Message for AdminCommand {}
+
+fn command(user: &str, command: &str) -> AdminCommand {
+    AdminCommand {
+        user: user.to_string(),
+        command: command.to_string(),
+    }
+}
+
+fn is_admin(event: &AdminCommand) -> bool {
+    event.user == "admin"
+}
+
+static EXTRACTIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// Counts how often extraction runs.
+struct Command(String);
+
+impl FromEvent<AdminCommand> for Command {
+    type Error = std::convert::Infallible;
+
+    fn from_event(event: &AdminCommand) -> Result<Self, Self::Error> {
+        EXTRACTIONS.fetch_add(1, Ordering::SeqCst);
+        Ok(Command(event.command.clone()))
+    }
+}
+
+static HANDLED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+#[risten::subscribe(filter = is_admin, name = "admin-commands", tags = ["admin", "audit"])]
+async fn on_admin_command(_event: &AdminCommand, command: Command) {
+    HANDLED.lock().unwrap().push(format!("admin:{}", command.0));
+}
+
+static BOOTSTRAPS: AtomicUsize = AtomicUsize::new(0);
+
+#[risten::subscribe(once)]
+async fn bootstrap(_event: AdminCommand) {
+    BOOTSTRAPS.fetch_add(1, Ordering::SeqCst);
+}
+
+#[tokio::test]
+async fn test_subscribe_options() {
+    let router = DispatchRouter::<AdminCommand>::new();
+
+    // The filter runs before extraction; `bootstrap` runs on the first event
+    let result = router.route(&command("guest", "ban")).await.unwrap();
+    assert_eq!(result.executed_count, 1);
+    assert_eq!(EXTRACTIONS.load(Ordering::SeqCst), 0);
+    assert!(HANDLED.lock().unwrap().is_empty());
+
+    // `bootstrap` is now disabled
+    let result = router.route(&command("admin", "ban")).await.unwrap();
+    assert_eq!(result.executed_count, 1);
+    assert_eq!(EXTRACTIONS.load(Ordering::SeqCst), 1);
+    assert_eq!(*HANDLED.lock().unwrap(), ["admin:ban"]);
+
+    router.route(&command("admin", "kick")).await.unwrap();
+    assert_eq!(BOOTSTRAPS.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn test_registration_metadata() {
+    let registrations: Vec<_> = DispatchRouter::<AdminCommand>::registrations().collect();
+    assert_eq!(registrations.len(), 2);
+
+    let admin = registrations
+        .iter()
+        .find(|reg| reg.name == Some("admin-commands"))
+        .unwrap();
+    assert_eq!(admin.tags, ["admin", "audit"]);
+    assert!(admin.has_tag("audit"));
+
+    // The name defaults to the function name
+    let once = registrations
+        .iter()
+        .find(|reg| reg.name == Some("bootstrap"))
+        .unwrap();
+    assert!(once.tags.is_empty());
+}
+
+static FLAKY_RUNS: AtomicUsize = AtomicUsize::new(0);
+
+/// Fails its first run, after yielding to the other calls.
+struct FlakyBootstrap;
+
+impl Handler<AdminCommand> for FlakyBootstrap {
+    type Output = Result<(), ExtractError>;
+
+    async fn call(&self, _event: AdminCommand) -> Self::Output {
+        let run = FLAKY_RUNS.fetch_add(1, Ordering::SeqCst);
+        tokio::task::yield_now().await;
+        if run == 0 {
+            return Err(ExtractError::failed("not ready"));
+        }
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_once_skips_concurrent_calls() {
+    let handler = OnceHandler::new(FlakyBootstrap);
+
+    // The second call arrives while the first runs, and is skipped
+    let (first, second) = tokio::join!(
+        handler.call(command("admin", "ban")),
+        handler.call(command("admin", "kick")),
+    );
+    assert!(!first.unwrap_err().is_rejection());
+    assert!(second.unwrap_err().is_rejection());
+    assert!(!handler.is_done());
+
+    // The failed run left the handler enabled
+    handler.call(command("admin", "ban")).await.unwrap();
+    assert!(handler.is_done());
+    assert!(
+        handler
+            .call(command("admin", "ban"))
+            .await
+            .unwrap_err()
+            .is_rejection()
+    );
+    assert_eq!(FLAKY_RUNS.load(Ordering::SeqCst), 2);
+}
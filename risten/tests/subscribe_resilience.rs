@@ -0,0 +1,137 @@
+//! Tests for the `timeout`, `retries` and `concurrency` options of `#[subscribe]`.
+
+#![cfg(all(feature = "macros", feature = "resilience"))]
+
+use risten::hooks::retry::RetryHook;
+use risten::{
+    BoxError, DispatchRouter, ExtractError, Hook, HookError, HookResult, Message, Router,
+};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+#[derive(Clone, Debug)]
+struct SlowEvent;
+
+impl Message for SlowEvent {}
+
+#[risten::subscribe(timeout = "10ms")]
+async fn too_slow(_event: SlowEvent) {
+    tokio::time::sleep(Duration::from_secs(5)).await;
+}
+
+#[tokio::test]
+async fn test_timeout_fails_with_hook_error() {
+    let router = DispatchRouter::<SlowEvent>::new();
+    let err = router.route(&SlowEvent).await.unwrap_err();
+
+    let source = std::error::Error::source(&err).unwrap();
+    assert!(matches!(
+        source.downcast_ref::<HookError>(),
+        Some(HookError::Timeout(duration)) if duration == &Duration::from_millis(10)
+    ));
+}
+
+#[derive(Clone, Debug)]
+struct FlakyEvent;
+
+impl Message for FlakyEvent {}
+
+#[derive(Debug)]
+struct Unavailable;
+
+impl std::fmt::Display for Unavailable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "service unavailable")
+    }
+}
+
+impl std::error::Error for Unavailable {}
+
+static ATTEMPTS: AtomicUsize = AtomicUsize::new(0);
+
+#[risten::subscribe(retries = 3, backoff = "1ms")]
+async fn flaky(_event: FlakyEvent) -> Result<(), Unavailable> {
+    if ATTEMPTS.fetch_add(1, Ordering::SeqCst) < 2 {
+        return Err(Unavailable);
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_retries_until_success() {
+    let router = DispatchRouter::<FlakyEvent>::new();
+    let result = router.route(&FlakyEvent).await.unwrap();
+    assert_eq!(result.executed_count, 1);
+    assert_eq!(ATTEMPTS.load(Ordering::SeqCst), 3);
+}
+
+#[derive(Clone, Debug)]
+struct BrokenEvent;
+
+impl Message for BrokenEvent {}
+
+static BROKEN_ATTEMPTS: AtomicUsize = AtomicUsize::new(0);
+
+#[risten::subscribe(retries = 2, backoff = "1ms")]
+async fn broken(_event: &BrokenEvent) -> Result<(), Unavailable> {
+    BROKEN_ATTEMPTS.fetch_add(1, Ordering::SeqCst);
+    Err(Unavailable)
+}
+
+#[tokio::test]
+async fn test_retries_exhausted() {
+    let router = DispatchRouter::<BrokenEvent>::new();
+    let err = router.route(&BrokenEvent).await.unwrap_err();
+    assert!(err.to_string().contains("service unavailable"));
+    assert_eq!(BROKEN_ATTEMPTS.load(Ordering::SeqCst), 3);
+}
+
+#[derive(Clone, Debug)]
+struct JobEvent;
+
+impl Message for JobEvent {}
+
+static RUNNING: AtomicUsize = AtomicUsize::new(0);
+static MAX_RUNNING: AtomicUsize = AtomicUsize::new(0);
+
+#[risten::subscribe(concurrency = 1)]
+async fn serial_job(_event: JobEvent) {
+    let running = RUNNING.fetch_add(1, Ordering::SeqCst) + 1;
+    MAX_RUNNING.fetch_max(running, Ordering::SeqCst);
+    tokio::time::sleep(Duration::from_millis(5)).await;
+    RUNNING.fetch_sub(1, Ordering::SeqCst);
+}
+
+#[tokio::test]
+async fn test_concurrency_limit() {
+    let router = DispatchRouter::<JobEvent>::new();
+    let (a, b, c) = tokio::join!(
+        router.route(&JobEvent),
+        router.route(&JobEvent),
+        router.route(&JobEvent),
+    );
+    for result in [a, b, c] {
+        assert_eq!(result.unwrap().executed_count, 1);
+    }
+    assert_eq!(MAX_RUNNING.load(Ordering::SeqCst), 1);
+}
+
+static SKIPPED_ATTEMPTS: AtomicUsize = AtomicUsize::new(0);
+
+/// Rejects every event softly.
+struct SkipAll;
+
+impl Hook<JobEvent> for SkipAll {
+    async fn on_event(&self, _event: &JobEvent) -> Result<HookResult, BoxError> {
+        SKIPPED_ATTEMPTS.fetch_add(1, Ordering::SeqCst);
+        Err(ExtractError::skipped("not for this hook").into())
+    }
+}
+
+#[tokio::test]
+async fn test_retry_hook_skips_rejections() {
+    let hook = RetryHook::new(SkipAll, 3, Duration::from_millis(1));
+    let err = hook.on_event(&JobEvent).await.unwrap_err();
+    assert!(err.downcast_ref::<ExtractError>().unwrap().is_rejection());
+    assert_eq!(SKIPPED_ATTEMPTS.load(Ordering::SeqCst), 1);
+}
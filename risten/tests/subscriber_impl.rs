@@ -0,0 +1,206 @@
+//! Tests for `#[subscriber]` impl blocks registered at runtime.
+
+#![cfg(feature = "macros")]
+
+use risten::routing::Subscriber;
+use risten::{DispatchRouter, FromEvent, FromEventGat, Message, Router};
+use std::convert::Infallible;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone, Debug)]
+struct JoinEvent {
+    user: String,
+}
+
+impl Message for JoinEvent {}
+
+#[derive(Clone, Debug)]
+struct ChatEvent {
+    user: String,
+    content: String,
+}
+
+impl Message for ChatEvent {}
+
+/// Extracts the chat author.
+struct Author(String);
+
+impl FromEvent<ChatEvent> for Author {
+    type Error = std::convert::Infallible;
+
+    fn from_event(event: &ChatEvent) -> Result<Self, Self::Error> {
+        Ok(Author(event.user.clone()))
+    }
+}
+
+fn is_command(event: &ChatEvent) -> bool {
+    event.content.starts_with('!')
+}
+
+#[derive(Default)]
+struct Greeter {
+    joined: AtomicUsize,
+    commands: Mutex<Vec<String>>,
+}
+
+#[risten::subscriber]
+impl Greeter {
+    #[on]
+    async fn on_join(&self, event: JoinEvent) {
+        assert!(!event.user.is_empty());
+        self.joined.fetch_add(1, Ordering::SeqCst);
+    }
+
+    #[on(filter = is_command)]
+    async fn on_command(&self, event: &ChatEvent, author: Author) {
+        let entry = format!("{}:{}", author.0, event.content);
+        self.commands.lock().unwrap().push(entry);
+    }
+
+    /// Not a subscription.
+    fn joined(&self) -> usize {
+        self.joined.load(Ordering::SeqCst)
+    }
+}
+
+fn join(user: &str) -> JoinEvent {
+    JoinEvent {
+        user: user.to_string(),
+    }
+}
+
+fn chat(user: &str, content: &str) -> ChatEvent {
+    ChatEvent {
+        user: user.to_string(),
+        content: content.to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_subscriber_shares_state_until_unregistered() {
+    let greeter = Arc::new(Greeter::default());
+    let subscription = Arc::clone(&greeter).subscribe();
+    assert_eq!(subscription.len(), 2);
+    assert_eq!(DispatchRouter::<JoinEvent>::handler_count(), 1);
+
+    let joins = DispatchRouter::<JoinEvent>::new();
+    let chats = DispatchRouter::<ChatEvent>::new();
+
+    joins.route(&join("alice")).await.unwrap();
+    joins.route(&join("bob")).await.unwrap();
+    chats.route(&chat("alice", "hello")).await.unwrap();
+    chats.route(&chat("bob", "!ping")).await.unwrap();
+
+    assert_eq!(greeter.joined(), 2);
+    assert_eq!(*greeter.commands.lock().unwrap(), ["bob:!ping"]);
+
+    subscription.unregister();
+    assert_eq!(DispatchRouter::<JoinEvent>::handler_count(), 0);
+
+    let result = joins.route(&join("carol")).await.unwrap();
+    assert_eq!(result.executed_count, 0);
+    assert_eq!(greeter.joined(), 2);
+
+    // The handlers released their `Arc`s
+    assert_eq!(Arc::strong_count(&greeter), 1);
+}
+
+#[derive(Clone, Debug)]
+struct LeaveEvent;
+
+impl Message for LeaveEvent {}
+
+#[derive(Default)]
+struct Farewell {
+    left: AtomicUsize,
+}
+
+#[risten::subscriber]
+impl Farewell {
+    #[on]
+    async fn on_leave(&self, _event: LeaveEvent) {
+        self.left.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[tokio::test]
+async fn test_subscriber_resubscribes_after_unregister() {
+    let farewell = Arc::new(Farewell::default());
+    let leaves = DispatchRouter::<LeaveEvent>::new();
+
+    Arc::clone(&farewell).subscribe().unregister();
+    let result = leaves.route(&LeaveEvent).await.unwrap();
+    assert_eq!(result.executed_count, 0);
+
+    let subscription = Arc::clone(&farewell).subscribe();
+    let result = leaves.route(&LeaveEvent).await.unwrap();
+    assert_eq!(result.executed_count, 1);
+    assert_eq!(farewell.left.load(Ordering::SeqCst), 1);
+    subscription.unregister();
+}
+
+#[derive(Clone, Debug)]
+struct TopicEvent {
+    topic: String,
+    body: String,
+}
+
+impl Message for TopicEvent {}
+
+/// Borrows the topic of a topic event.
+struct Topic;
+
+impl FromEventGat<TopicEvent> for Topic {
+    type Output<'a> = &'a str;
+    type Error = Infallible;
+
+    fn extract(event: &TopicEvent) -> Result<&str, Self::Error> {
+        Ok(&event.topic)
+    }
+}
+
+/// Borrows the body of a topic event.
+struct Body;
+
+impl FromEventGat<TopicEvent> for Body {
+    type Output<'a> = &'a str;
+    type Error = Infallible;
+
+    fn extract(event: &TopicEvent) -> Result<&str, Self::Error> {
+        Ok(&event.body)
+    }
+}
+
+#[derive(Default)]
+struct Archive {
+    entries: Mutex<Vec<String>>,
+}
+
+#[risten::subscriber]
+impl Archive {
+    #[on(TopicEvent)]
+    async fn on_topic(&self, #[extract(Topic)] topic: &str, #[extract(Body)] body: &str) {
+        self.entries
+            .lock()
+            .unwrap()
+            .push(format!("{topic}: {body}"));
+    }
+}
+
+#[tokio::test]
+async fn test_subscriber_named_extractors() {
+    let archive = Arc::new(Archive::default());
+    let subscription = Arc::clone(&archive).subscribe();
+
+    let event = TopicEvent {
+        topic: "news".to_string(),
+        body: "hello".to_string(),
+    };
+    DispatchRouter::<TopicEvent>::new()
+        .route(&event)
+        .await
+        .unwrap();
+    assert_eq!(*archive.entries.lock().unwrap(), ["news: hello"]);
+    subscription.unregister();
+}
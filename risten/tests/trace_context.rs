@@ -0,0 +1,223 @@
+//! Tests for W3C trace context propagation.
+
+use risten::context::{self, DispatchContext};
+use risten::{
+    BoxError, Envelope, Hook, HookResult, Message, Metadata, Router, StaticRouter, TRACEPARENT,
+    TRACESTATE, TraceContext, static_hooks,
+};
+use std::sync::Mutex;
+
+mod common;
+use common::Inspect;
+
+const TRACEPARENT_VALUE: &str = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+
+#[derive(Clone, Debug)]
+struct Traced;
+
+impl Message for Traced {}
+
+#[test]
+fn test_parse_and_format() {
+    let trace = TraceContext::parse(TRACEPARENT_VALUE, Some("vendor=abc")).unwrap();
+    assert_eq!(trace.trace_id(), 0x4bf92f3577b34da6a3ce929d0e0e4736);
+    assert_eq!(trace.span_id(), 0x00f067aa0ba902b7);
+    assert!(trace.is_sampled());
+    assert_eq!(trace.tracestate(), Some("vendor=abc"));
+    assert_eq!(trace.traceparent(), TRACEPARENT_VALUE);
+
+    // Later versions may append fields
+    assert!(TraceContext::parse(&format!("01{}-extra", &TRACEPARENT_VALUE[2..]), None).is_some());
+
+    for invalid in [
+        "",
+        "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7",
+        "00-4BF92F3577B34DA6A3CE929D0E0E4736-00f067aa0ba902b7-01",
+        "00-00000000000000000000000000000000-00f067aa0ba902b7-01",
+        "00-4bf92f3577b34da6a3ce929d0e0e4736-0000000000000000-01",
+        "ff-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+        "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01-extra",
+    ] {
+        assert_eq!(TraceContext::parse(invalid, None), None, "{invalid}");
+    }
+}
+
+#[test]
+fn test_envelope_headers() {
+    let trace = TraceContext::parse(TRACEPARENT_VALUE, None).unwrap();
+    let envelope = Envelope::new(Traced).with_trace_context(&trace.clone().with_tracestate("a=1"));
+    assert_eq!(
+        envelope.metadata().header(TRACEPARENT),
+        Some(TRACEPARENT_VALUE)
+    );
+    assert_eq!(envelope.metadata().header(TRACESTATE), Some("a=1"));
+
+    let mut metadata = envelope.metadata().clone();
+    metadata.set_trace_context(&trace);
+    assert_eq!(metadata.header(TRACESTATE), None);
+    assert_eq!(metadata.trace_context(), Some(trace));
+}
+
+#[tokio::test]
+async fn test_route_restores_trace_context() {
+    let inspect = Inspect::default();
+
+    let parent = TraceContext::parse(TRACEPARENT_VALUE, None).unwrap();
+    let router = StaticRouter::new(static_hooks![inspect.clone()]);
+    router
+        .route(&Envelope::new(Traced).with_trace_context(&parent))
+        .await
+        .unwrap();
+    router.route(&Envelope::new(Traced)).await.unwrap();
+    router.route(&Traced).await.unwrap();
+
+    let seen = inspect.seen();
+    let trace = seen[0].trace_context().unwrap();
+    assert_eq!(trace.trace_id(), parent.trace_id());
+    assert_ne!(trace.span_id(), parent.span_id());
+    assert_eq!(seen[0].parent_span_id(), Some(parent.span_id()));
+    assert!(seen[1].trace_context().is_none());
+    assert!(seen[2].trace_context().is_none());
+}
+
+#[tokio::test]
+async fn test_trace_context_crosses_queue() {
+    let inspect = Inspect::default();
+
+    let parent = TraceContext::parse(TRACEPARENT_VALUE, Some("vendor=abc")).unwrap();
+    let outgoing = Envelope::new(Traced).with_trace_context(&parent);
+
+    // The metadata crosses the wire; the consumer rebuilds the envelope from it
+    let metadata = outgoing.metadata();
+    let wire = (
+        metadata.id().to_string(),
+        metadata.timestamp(),
+        metadata.correlation(),
+        metadata.headers().clone(),
+    );
+    let (tx, rx) = std::sync::mpsc::channel();
+    tx.send(wire).unwrap();
+    let hook = inspect.clone();
+    let consumer = tokio::spawn(async move {
+        let (id, timestamp, correlation, headers) = rx.recv().unwrap();
+        let metadata = Metadata::from_parts(id.parse().unwrap(), timestamp, correlation, headers);
+        let incoming = Envelope::from_parts(Traced, metadata);
+        let router = StaticRouter::new(static_hooks![hook]);
+        router.route(&incoming).await.unwrap();
+    });
+    consumer.await.unwrap();
+
+    let seen = inspect.seen();
+    assert_eq!(seen[0].event_id(), Some(outgoing.metadata().id()));
+    let trace = seen[0].trace_context().unwrap();
+    assert_eq!(trace.trace_id(), parent.trace_id());
+    assert_eq!(trace.tracestate(), Some("vendor=abc"));
+    assert_eq!(seen[0].parent_span_id(), Some(parent.span_id()));
+}
+
+#[cfg(all(feature = "macros", feature = "inventory"))]
+mod emit {
+    use super::*;
+    use risten::routing::{EmitError, Emitter};
+    use risten::{DispatchRouter, Envelope};
+
+    #[derive(Clone, Debug)]
+    struct OrderPlaced;
+
+    impl Message for OrderPlaced {}
+
+    #[derive(Clone, Debug)]
+    struct ChargeCard;
+
+    impl Message for ChargeCard {}
+
+    static ROUTES: Mutex<Vec<DispatchContext>> = Mutex::new(Vec::new());
+
+    #[risten::subscribe]
+    async fn on_order(_order: &Envelope<OrderPlaced>, emitter: Emitter) -> Result<(), EmitError> {
+        ROUTES.lock().unwrap().push(context::current().unwrap());
+        emitter.emit(Envelope::new(ChargeCard)).await?;
+        Ok(())
+    }
+
+    #[risten::subscribe]
+    async fn charge_card(_charge: &Envelope<ChargeCard>) {
+        ROUTES.lock().unwrap().push(context::current().unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_emitted_events_continue_trace() {
+        let parent = TraceContext::parse(TRACEPARENT_VALUE, None).unwrap();
+        let router = DispatchRouter::<Envelope<OrderPlaced>>::new();
+        router
+            .route(&Envelope::new(OrderPlaced).with_trace_context(&parent))
+            .await
+            .unwrap();
+
+        let routes = ROUTES.lock().unwrap();
+        let order = routes[0].trace_context().unwrap();
+        let charge = routes[1].trace_context().unwrap();
+        assert_eq!(charge.trace_id(), parent.trace_id());
+        assert_eq!(routes[1].parent_span_id(), Some(order.span_id()));
+    }
+}
+
+#[cfg(feature = "opentelemetry")]
+mod otel {
+    use super::*;
+    use opentelemetry::propagation::{Extractor, Injector, TextMapPropagator};
+    use opentelemetry::trace::TraceContextExt;
+    use opentelemetry::{Context, global};
+    use risten::hooks::propagation::{PropagationHook, to_span_context};
+
+    /// A minimal W3C propagator, standing in for the SDK's.
+    #[derive(Debug)]
+    struct W3cPropagator;
+
+    impl TextMapPropagator for W3cPropagator {
+        fn inject_context(&self, _cx: &Context, _injector: &mut dyn Injector) {}
+
+        fn extract_with_context(&self, cx: &Context, extractor: &dyn Extractor) -> Context {
+            match extractor
+                .get(TRACEPARENT)
+                .and_then(|value| TraceContext::parse(value, extractor.get(TRACESTATE)))
+            {
+                Some(trace) => cx.with_remote_span_context(to_span_context(&trace)),
+                None => cx.clone(),
+            }
+        }
+
+        fn fields(&self) -> opentelemetry::propagation::text_map_propagator::FieldIter<'_> {
+            opentelemetry::propagation::text_map_propagator::FieldIter::new(&[])
+        }
+    }
+
+    static TRACE_IDS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    struct RecordTraceId;
+
+    impl Hook<Envelope<Traced>> for RecordTraceId {
+        async fn on_event(&self, _event: &Envelope<Traced>) -> Result<HookResult, BoxError> {
+            let trace_id = Context::current().span().span_context().trace_id();
+            TRACE_IDS.lock().unwrap().push(trace_id.to_string());
+            Ok(HookResult::Next)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_propagation_hook() {
+        global::set_text_map_propagator(W3cPropagator);
+
+        let parent = TraceContext::parse(TRACEPARENT_VALUE, None).unwrap();
+        let router = StaticRouter::new(static_hooks![PropagationHook::new(RecordTraceId)]);
+        router
+            .route(&Envelope::new(Traced).with_trace_context(&parent))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *TRACE_IDS.lock().unwrap(),
+            ["4bf92f3577b34da6a3ce929d0e0e4736"]
+        );
+    }
+}
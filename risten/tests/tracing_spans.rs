@@ -0,0 +1,191 @@
+//! Tests for the tracing spans opened by routers and `#[subscribe(instrument)]`.
+
+#![cfg(all(feature = "tracing", feature = "macros"))]
+
+use risten::{
+    BoxError, DispatchRouter, Hook, HookResult, Message, Router, StaticRouter, static_hooks,
+};
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+#[derive(Debug, Default)]
+struct SpanData {
+    name: &'static str,
+    parent: Option<u64>,
+    fields: HashMap<&'static str, String>,
+}
+
+impl Visit for SpanData {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.fields.insert(field.name(), value.to_string());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.fields.insert(field.name(), format!("{value:?}"));
+    }
+}
+
+/// Records every span and event; events are stored as spans named `event`.
+#[derive(Clone, Default)]
+struct Capture {
+    spans: Arc<Mutex<Vec<SpanData>>>,
+    stack: Arc<Mutex<Vec<u64>>>,
+}
+
+impl Capture {
+    fn current(&self) -> Option<u64> {
+        self.stack.lock().unwrap().last().copied()
+    }
+
+    fn spans(&self, name: &str) -> Vec<(u64, Option<u64>, HashMap<&'static str, String>)> {
+        let spans = self.spans.lock().unwrap();
+        (1..)
+            .zip(spans.iter())
+            .filter(|(_, span)| span.name == name)
+            .map(|(id, span)| (id, span.parent, span.fields.clone()))
+            .collect()
+    }
+}
+
+impl Subscriber for Capture {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, attrs: &Attributes<'_>) -> Id {
+        let mut span = SpanData {
+            name: attrs.metadata().name(),
+            parent: attrs.parent().map(Id::into_u64).or_else(|| self.current()),
+            ..SpanData::default()
+        };
+        attrs.record(&mut span);
+        let mut spans = self.spans.lock().unwrap();
+        spans.push(span);
+        Id::from_u64(spans.len() as u64)
+    }
+
+    fn record(&self, id: &Id, values: &Record<'_>) {
+        let mut spans = self.spans.lock().unwrap();
+        values.record(&mut spans[id.into_u64() as usize - 1]);
+    }
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut data = SpanData {
+            name: "event",
+            parent: self.current(),
+            ..SpanData::default()
+        };
+        event.record(&mut data);
+        self.spans.lock().unwrap().push(data);
+    }
+
+    fn enter(&self, span: &Id) {
+        self.stack.lock().unwrap().push(span.into_u64());
+    }
+
+    fn exit(&self, _span: &Id) {
+        self.stack.lock().unwrap().pop();
+    }
+}
+
+#[derive(Clone, Debug)]
+struct MessageEvent;
+
+impl Message for MessageEvent {}
+
+struct Pass;
+
+impl Hook<MessageEvent> for Pass {
+    async fn on_event(&self, _event: &MessageEvent) -> Result<HookResult, BoxError> {
+        Ok(HookResult::Next)
+    }
+}
+
+struct Consume;
+
+impl Hook<MessageEvent> for Consume {
+    async fn on_event(&self, _event: &MessageEvent) -> Result<HookResult, BoxError> {
+        Ok(HookResult::Stop)
+    }
+}
+
+#[tokio::test]
+async fn test_static_router_spans() {
+    let capture = Capture::default();
+    let _guard = tracing::subscriber::set_default(capture.clone());
+
+    let router = StaticRouter::new(static_hooks![Pass, Consume, Pass]);
+    router.route(&MessageEvent).await.unwrap();
+
+    let routes = capture.spans("risten.route");
+    assert_eq!(routes.len(), 1);
+    let (route_id, _, route) = &routes[0];
+    assert_eq!(route["event"], std::any::type_name::<MessageEvent>());
+    assert_eq!(route["outcome"], "stop");
+    assert!(route.contains_key("elapsed_us"));
+
+    let hooks = capture.spans("risten.hook");
+    let names: Vec<_> = hooks.iter().map(|(_, _, f)| f["hook"].clone()).collect();
+    let outcomes: Vec<_> = hooks.iter().map(|(_, _, f)| f["outcome"].clone()).collect();
+    assert_eq!(
+        names,
+        [
+            std::any::type_name::<Pass>(),
+            std::any::type_name::<Consume>(),
+        ]
+    );
+    assert_eq!(outcomes, ["next", "stop"]);
+    assert!(
+        hooks
+            .iter()
+            .all(|(_, parent, _)| parent == &Some(*route_id))
+    );
+}
+
+#[derive(Clone, Debug)]
+struct OrderPlaced;
+
+impl Message for OrderPlaced {}
+
+#[derive(Debug, thiserror::Error)]
+#[error("payment declined")]
+struct Declined;
+
+#[risten::subscribe(instrument, priority = 5, name = "charge")]
+async fn charge(_event: OrderPlaced) -> Result<(), Declined> {
+    Err(Declined)
+}
+
+#[tokio::test]
+async fn test_dispatch_and_instrument_spans() {
+    let capture = Capture::default();
+    let _guard = tracing::subscriber::set_default(capture.clone());
+
+    let router = DispatchRouter::<OrderPlaced>::new();
+    assert!(router.route(&OrderPlaced).await.is_err());
+
+    let (route_id, _, route) = capture.spans("risten.route").remove(0);
+    assert_eq!(route["outcome"], "error");
+
+    let (hook_id, parent, hook) = capture.spans("risten.hook").remove(0);
+    assert_eq!(parent, Some(route_id));
+    assert_eq!(hook["hook"], "charge");
+    assert_eq!(hook["priority"], "5");
+    assert_eq!(hook["outcome"], "error");
+
+    let (instrument_id, parent, instrument) = capture.spans("risten.instrument").remove(0);
+    assert_eq!(parent, Some(hook_id));
+    assert_eq!(instrument["hook"], "charge");
+    assert_eq!(instrument["outcome"], "error");
+
+    let events = capture.spans("event");
+    assert!(events.iter().any(|(_, parent, fields)| {
+        parent == &Some(instrument_id) && fields["error"].contains("payment declined")
+    }));
+}
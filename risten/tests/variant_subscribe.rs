@@ -0,0 +1,98 @@
+//! Tests for variant-level subscriptions on enum events.
+
+#![cfg(feature = "macros")]
+
+use risten::{DispatchRouter, Message, Router};
+use std::sync::Mutex;
+
+#[derive(Clone, Debug)]
+struct MessageEvent {
+    content: String,
+}
+
+impl Message for MessageEvent {}
+
+#[derive(Clone, Debug)]
+struct ReadyEvent {
+    session_id: u64,
+}
+
+impl Message for ReadyEvent {}
+
+#[risten::dispatch]
+#[derive(Clone, Debug, Message)]
+enum AppEvent {
+    Message(MessageEvent),
+    Ready(ReadyEvent),
+    Shutdown,
+}
+
+static SEEN: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+fn seen(entry: String) {
+    SEEN.lock().unwrap().push(entry);
+}
+
+#[risten::subscribe(AppEvent::Message)]
+async fn on_app_message(event: MessageEvent) {
+    seen(format!("message:{}", event.content));
+}
+
+#[risten::subscribe(AppEvent::Ready)]
+async fn on_app_ready(event: &ReadyEvent) {
+    seen(format!("ready:{}", event.session_id));
+}
+
+#[risten::subscribe]
+async fn on_any_app_event(event: &AppEvent) {
+    seen(format!("any:{}", event.variant_name()));
+}
+
+#[test]
+fn test_derived_variant() {
+    let event = AppEvent::Ready(ReadyEvent { session_id: 1 });
+    assert_eq!(Message::variant(&event), Some("Ready"));
+    assert_eq!(Message::variant(&AppEvent::Shutdown), Some("Shutdown"));
+    assert_eq!(Message::variant(&"not an enum"), None);
+}
+
+#[tokio::test]
+async fn test_variant_subscribers_receive_payload() {
+    let router = DispatchRouter::<AppEvent>::new();
+
+    let event = AppEvent::Message(MessageEvent {
+        content: "hi".to_string(),
+    });
+    // Only the `Message` subscriber and the whole-enum subscriber run
+    assert_eq!(router.route(&event).await.unwrap().executed_count, 2);
+
+    let result = router
+        .route(&AppEvent::Ready(ReadyEvent { session_id: 7 }))
+        .await;
+    assert_eq!(result.unwrap().executed_count, 2);
+
+    let result = router.route(&AppEvent::Shutdown).await;
+    assert_eq!(result.unwrap().executed_count, 1);
+
+    let mut seen = SEEN.lock().unwrap().clone();
+    seen.sort();
+    assert_eq!(
+        seen,
+        [
+            "any:Message",
+            "any:Ready",
+            "any:Shutdown",
+            "message:hi",
+            "ready:7"
+        ]
+    );
+}
+
+#[test]
+fn test_registration_variant() {
+    let mut variants: Vec<_> = DispatchRouter::<AppEvent>::registrations()
+        .map(|reg| reg.variant)
+        .collect();
+    variants.sort();
+    assert_eq!(variants, [None, Some("Message"), Some("Ready")]);
+}